@@ -0,0 +1,110 @@
+use std::fmt::Display;
+use std::slice::Iter;
+
+use crate::xy::XY;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+use Direction::*;
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [North, South, East, West];
+
+    pub fn iter() -> Iter<'static, Direction> {
+        Self::ALL.iter()
+    }
+
+    /// the (dx, dy) a single step in this direction moves by
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            North => (0, -1),
+            South => (0, 1),
+            East => (1, 0),
+            West => (-1, 0),
+        }
+    }
+
+    /// returns None if stepping would cause the coordinates to go
+    /// negative (which would panic because x & y are unsigned)
+    pub fn step(self, xy: XY) -> Option<XY> {
+        xy.step(self)
+    }
+
+    pub fn clockwise(self) -> Direction {
+        match self {
+            North => East,
+            East => South,
+            South => West,
+            West => North,
+        }
+    }
+
+    pub fn counter_clockwise(self) -> Direction {
+        match self {
+            North => West,
+            West => South,
+            South => East,
+            East => North,
+        }
+    }
+
+    pub fn invert(self) -> Direction {
+        self.clockwise().clockwise()
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            North => f.write_str("North"),
+            South => f.write_str("South"),
+            East => f.write_str("East"),
+            West => f.write_str("West"),
+        }
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = char;
+
+    /// parses the arrows used by the puzzles (`^`, `v`, `>` & `<`)
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '^' => Ok(North),
+            'v' => Ok(South),
+            '>' => Ok(East),
+            '<' => Ok(West),
+            _ => Err(value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_clockwise_is_undone_by_counter_clockwise() {
+        for d in Direction::iter() {
+            assert_eq!(d.clockwise().counter_clockwise(), *d);
+        }
+    }
+
+    #[test]
+    fn test_invert() {
+        assert_eq!(North.invert(), South);
+        assert_eq!(East.invert(), West);
+    }
+
+    #[test]
+    fn test_parse_arrow() {
+        assert_eq!(Direction::try_from('^'), Ok(North));
+        assert_eq!(Direction::try_from('x'), Err('x'));
+    }
+}
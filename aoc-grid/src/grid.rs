@@ -0,0 +1,337 @@
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use crate::direction::Direction;
+use crate::xy::XY;
+
+/// A rectangular grid stored row by row. Every lookup is bounds checked, so
+/// stepping off any edge yields `None` rather than wrapping or panicking.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+#[derive(Debug, Error, PartialEq)]
+pub enum GridParseError<E> {
+    #[error("Line {line} has {found} cells but the first line has {expected}")]
+    NotRectangular {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+
+    #[error("Could not parse {c:?} at {xy}")]
+    InvalidCell {
+        c: char,
+        xy: XY,
+        #[source]
+        source: E,
+    },
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(XY) -> T) -> Grid<T> {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| XY(x, y)))
+            .map(&mut f)
+            .collect();
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses a char map, converting each char with `f`. The input and every
+    /// line are trimmed first so indented examples in tests parse as-is.
+    pub fn parse<E>(
+        s: &str,
+        mut f: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Grid<T>, GridParseError<E>> {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+
+        for (y, line) in s.trim().lines().enumerate() {
+            let line = line.trim();
+            let found = line.chars().count();
+            let expected = *width.get_or_insert(found);
+            if found != expected {
+                return Err(GridParseError::NotRectangular {
+                    line: y + 1,
+                    expected,
+                    found,
+                });
+            }
+
+            for (x, c) in line.chars().enumerate() {
+                let cell = f(c).map_err(|source| GridParseError::InvalidCell {
+                    c,
+                    xy: XY(x, y),
+                    source,
+                })?;
+                cells.push(cell);
+            }
+
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, XY(x, y): XY) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, xy: XY) -> Option<&T> {
+        self.contains(xy).then(|| &self.cells[self.index_of(xy)])
+    }
+
+    pub fn get_mut(&mut self, xy: XY) -> Option<&mut T> {
+        match self.contains(xy) {
+            true => {
+                let i = self.index_of(xy);
+                Some(&mut self.cells[i])
+            }
+            false => None,
+        }
+    }
+
+    /// every point & value, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (XY, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, value)| (XY(i % self.width, i / self.width), value))
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = XY> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| XY(x, y)))
+    }
+
+    /// the first point, row by row, whose value matches `predicate`
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<XY> {
+        self.iter()
+            .find_map(|(xy, value)| predicate(value).then_some(xy))
+    }
+
+    /// like [`XY::step`] but also returns None when stepping off the far edges
+    pub fn step(&self, xy: XY, direction: Direction) -> Option<XY> {
+        xy.step(direction).filter(|next| self.contains(*next))
+    }
+
+    /// the orthogonal neighbours of `xy` that are inside the grid
+    pub fn neighbors(&self, xy: XY) -> impl Iterator<Item = XY> {
+        xy.neighbors().filter(|next| self.contains(*next))
+    }
+
+    /// the orthogonal & diagonal neighbours of `xy` that are inside the grid
+    pub fn neighbors_8(&self, xy: XY) -> impl Iterator<Item = XY> {
+        xy.neighbors_8().filter(|next| self.contains(*next))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// a copy of the grid turned a quarter turn clockwise, so the left column
+    /// becomes the top row
+    pub fn rotate_clockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.height, self.width, |XY(x, y)| {
+            self[XY(y, self.height - 1 - x)].clone()
+        })
+    }
+
+    /// a copy of the grid turned a quarter turn counter clockwise, so the top
+    /// row becomes the left column
+    pub fn rotate_counter_clockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.height, self.width, |XY(x, y)| {
+            self[XY(self.width - 1 - y, x)].clone()
+        })
+    }
+
+    fn index_of(&self, XY(x, y): XY) -> usize {
+        y * self.width + x
+    }
+}
+
+impl<T> Index<XY> for Grid<T> {
+    type Output = T;
+
+    /// panics if `xy` is outside the grid
+    fn index(&self, xy: XY) -> &Self::Output {
+        self.get(xy)
+            .unwrap_or_else(|| panic!("{xy} is outside of a {}x{} grid", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<XY> for Grid<T> {
+    /// panics if `xy` is outside the grid
+    fn index_mut(&mut self, xy: XY) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(xy)
+            .unwrap_or_else(|| panic!("{xy} is outside of a {width}x{height} grid"))
+    }
+}
+
+impl<T: TryFrom<char>> FromStr for Grid<T> {
+    type Err = GridParseError<T::Error>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse(s, T::try_from)
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.cells.chunks(self.width.max(1)) {
+            for value in row {
+                write!(f, "{value}")?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use Direction::*;
+
+    fn example() -> Grid<char> {
+        "
+            ab
+            cd
+            ef
+        "
+        .parse()
+        .unwrap()
+    }
+
+    #[test]
+    fn test_parse_dimensions() {
+        let grid = example();
+        assert_eq!((grid.width(), grid.height()), (2, 3));
+        assert_eq!(grid[XY(1, 2)], 'f');
+    }
+
+    #[test]
+    fn test_parse_empty() {
+        let grid: Grid<char> = "".parse().unwrap();
+        assert_eq!((grid.width(), grid.height()), (0, 0));
+        assert_eq!(grid.to_string(), "");
+    }
+
+    #[test]
+    fn test_parse_not_rectangular() {
+        let result = "ab\nc".parse::<Grid<char>>();
+        assert_eq!(
+            result,
+            Err(GridParseError::NotRectangular {
+                line: 2,
+                expected: 2,
+                found: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_cell() {
+        let result = Grid::parse("12\n3x", |c| c.to_digit(10).ok_or(()));
+        assert!(matches!(
+            result,
+            Err(GridParseError::InvalidCell {
+                c: 'x',
+                xy: XY(1, 1),
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_get_out_of_bounds() {
+        let grid = example();
+        assert_eq!(grid.get(XY(2, 0)), None);
+        assert_eq!(grid.get(XY(0, 3)), None);
+    }
+
+    #[test]
+    fn test_step_off_the_far_edge() {
+        let grid = example();
+        assert_eq!(grid.step(XY(1, 0), East), None);
+        assert_eq!(grid.step(XY(0, 0), East), Some(XY(1, 0)));
+    }
+
+    #[test]
+    fn test_neighbors_in_corner() {
+        let grid = example();
+        let result: Vec<XY> = grid.neighbors(XY(1, 2)).collect();
+        assert_eq!(result, vec![XY(1, 1), XY(0, 2)]);
+        assert_eq!(grid.neighbors_8(XY(1, 2)).count(), 3);
+    }
+
+    #[test]
+    fn test_find() {
+        assert_eq!(example().find(|c| *c == 'd'), Some(XY(1, 1)));
+    }
+
+    #[test]
+    fn test_rotate_clockwise() {
+        let result = example().rotate_clockwise();
+        assert_eq!(result.to_string(), "eca\nfdb\n");
+    }
+
+    #[test]
+    fn test_rotate_counter_clockwise() {
+        let result = example().rotate_counter_clockwise();
+        assert_eq!(result.to_string(), "bdf\nace\n");
+        assert_eq!(result.rotate_clockwise(), example());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(example().to_string(), "ab\ncd\nef\n");
+    }
+}
@@ -0,0 +1,7 @@
+mod direction;
+mod grid;
+mod xy;
+
+pub use direction::Direction;
+pub use grid::{Grid, GridParseError};
+pub use xy::XY;
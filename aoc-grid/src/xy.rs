@@ -0,0 +1,141 @@
+use std::fmt::Display;
+use std::num::TryFromIntError;
+
+use crate::direction::Direction;
+
+/// An unsigned point on a grid. `x` grows to the east and `y` grows to the
+/// south, so `XY(0, 0)` is the top left corner of a puzzle input.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct XY(pub usize, pub usize);
+
+/// offsets of the 8 surrounding points, clockwise starting from the north
+const OFFSETS_8: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+impl XY {
+    pub fn new(x: usize, y: usize) -> XY {
+        XY(x, y)
+    }
+
+    pub fn x(&self) -> usize {
+        self.0
+    }
+
+    pub fn y(&self) -> usize {
+        self.1
+    }
+
+    /// returns None if stepping would cause the coordinates to go negative
+    pub fn step(self, direction: Direction) -> Option<XY> {
+        self.offset(direction.offset())
+    }
+
+    /// returns None if the offset would cause the coordinates to go negative
+    pub fn offset(self, (dx, dy): (isize, isize)) -> Option<XY> {
+        Some(XY(
+            self.0.checked_add_signed(dx)?,
+            self.1.checked_add_signed(dy)?,
+        ))
+    }
+
+    /// the up to 4 orthogonal neighbours that don't go negative. There is no
+    /// upper bound, use [`crate::Grid::neighbors`] when one is needed.
+    pub fn neighbors(self) -> impl Iterator<Item = XY> {
+        Direction::iter().filter_map(move |d| self.step(*d))
+    }
+
+    /// the up to 8 orthogonal & diagonal neighbours that don't go negative
+    pub fn neighbors_8(self) -> impl Iterator<Item = XY> {
+        OFFSETS_8.into_iter().filter_map(move |o| self.offset(o))
+    }
+
+    pub fn manhattan_distance(self, other: XY) -> usize {
+        self.0.abs_diff(other.0) + self.1.abs_diff(other.1)
+    }
+}
+
+impl Display for XY {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.0, self.1)
+    }
+}
+
+impl From<(usize, usize)> for XY {
+    fn from((x, y): (usize, usize)) -> Self {
+        XY(x, y)
+    }
+}
+
+impl From<XY> for (usize, usize) {
+    fn from(XY(x, y): XY) -> Self {
+        (x, y)
+    }
+}
+
+impl TryFrom<(isize, isize)> for XY {
+    type Error = TryFromIntError;
+
+    fn try_from((x, y): (isize, isize)) -> Result<Self, Self::Error> {
+        Ok(XY(x.try_into()?, y.try_into()?))
+    }
+}
+
+impl TryFrom<XY> for (isize, isize) {
+    type Error = TryFromIntError;
+
+    fn try_from(XY(x, y): XY) -> Result<Self, Self::Error> {
+        Ok((x.try_into()?, y.try_into()?))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use Direction::*;
+
+    #[test]
+    fn test_step() {
+        assert_eq!(XY(1, 1).step(North), Some(XY(1, 0)));
+        assert_eq!(XY(1, 1).step(South), Some(XY(1, 2)));
+        assert_eq!(XY(1, 1).step(East), Some(XY(2, 1)));
+        assert_eq!(XY(1, 1).step(West), Some(XY(0, 1)));
+    }
+
+    #[test]
+    fn test_step_past_zero() {
+        assert_eq!(XY(0, 0).step(North), None);
+        assert_eq!(XY(0, 0).step(West), None);
+    }
+
+    #[test]
+    fn test_neighbors_of_origin() {
+        let result: Vec<XY> = XY(0, 0).neighbors().collect();
+        assert_eq!(result, vec![XY(0, 1), XY(1, 0)]);
+    }
+
+    #[test]
+    fn test_neighbors_8() {
+        assert_eq!(XY(0, 0).neighbors_8().count(), 3);
+        assert_eq!(XY(1, 1).neighbors_8().count(), 8);
+    }
+
+    #[test]
+    fn test_signed_conversions() {
+        assert_eq!(XY::try_from((2isize, 3isize)), Ok(XY(2, 3)));
+        assert!(XY::try_from((-1isize, 3isize)).is_err());
+        assert_eq!(<(isize, isize)>::try_from(XY(2, 3)), Ok((2, 3)));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(XY(2, 3).to_string(), "(2, 3)");
+    }
+}
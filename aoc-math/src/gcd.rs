@@ -0,0 +1,25 @@
+/// (gcd, p, q) with a * p + b * q = gcd
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    match b {
+        0 => (a, 1, 0),
+        _ => {
+            let (gcd, p, q) = extended_gcd(b, a % b);
+            (gcd, q, p - a / b * q)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_extended_gcd() {
+        for (a, b, expected) in [(240, 46, 2), (101, 103, 1), (6, 4, 2), (7, 0, 7)] {
+            let (gcd, p, q) = extended_gcd(a, b);
+
+            assert_eq!(gcd, expected);
+            assert_eq!(a * p + b * q, gcd);
+        }
+    }
+}
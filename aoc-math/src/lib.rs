@@ -0,0 +1,3 @@
+mod gcd;
+
+pub use gcd::extended_gcd;
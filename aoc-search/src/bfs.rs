@@ -0,0 +1,90 @@
+use std::collections::VecDeque;
+use std::hash::Hash;
+
+use crate::search_result::SearchResult;
+
+/// Shortest paths from any of `starts` when every step costs 1. Stops once
+/// every state at the distance of the nearest goal has been explored, pass
+/// `|_| false` as `is_goal` to explore everything reachable.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> SearchResult<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut result = SearchResult::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if result.distances.insert(start.clone(), 0).is_none() {
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(state) = queue.pop_front() {
+        let cost = result.distances[&state];
+        if result.goal_cost().is_some_and(|best| cost > best) {
+            break;
+        }
+
+        if is_goal(&state) {
+            result.goals.push(state);
+            continue;
+        }
+
+        for next in successors(&state) {
+            if result.relax(&state, next.clone(), cost + 1) {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn successors(&(x, y): &(u8, u8)) -> Vec<(u8, u8)> {
+        [(x + 1, y), (x, y + 1)]
+            .into_iter()
+            .filter(|&(x, y)| x <= 2 && y <= 2)
+            .collect()
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let result = bfs([(0, 0)], successors, |xy| *xy == (1, 0));
+
+        assert_eq!(result.goal_cost(), Some(1));
+        assert_eq!(result.path(), Some(vec![(0, 0), (1, 0)]));
+    }
+
+    #[test]
+    fn test_every_tile_on_a_shortest_path() {
+        let result = bfs([(0, 0)], successors, |xy| *xy == (1, 1));
+
+        assert_eq!(result.goal_cost(), Some(2));
+        assert_eq!(result.on_optimal_paths().len(), 4);
+    }
+
+    #[test]
+    fn test_explores_everything_without_a_goal() {
+        let result = bfs([(0, 0)], successors, |_| false);
+
+        assert_eq!(result.distances().len(), 9);
+        assert_eq!(result.distance(&(2, 2)), Some(4));
+        assert_eq!(result.goals(), &[]);
+    }
+
+    #[test]
+    fn test_multiple_starts() {
+        let result = bfs([(0, 0), (2, 1)], successors, |xy| *xy == (2, 2));
+
+        assert_eq!(result.path(), Some(vec![(2, 1), (2, 2)]));
+    }
+}
@@ -0,0 +1,177 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::hash::Hash;
+
+use crate::search_result::SearchResult;
+
+/// Cheapest paths from any of `starts`. `successors` returns the states
+/// reachable from a state along with the cost of getting there.
+///
+/// The search stops once every state that could be on an optimal path to a
+/// goal has been settled. Pass `|_| false` as `is_goal` to settle every
+/// reachable state instead.
+pub fn dijkstra<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> SearchResult<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    astar(starts, successors, |_| 0, is_goal)
+}
+
+/// Like [`dijkstra`] but states are explored in order of their cost plus
+/// `heuristic`, which must never overestimate the remaining cost to a goal.
+pub fn astar<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> usize,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> SearchResult<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut result = SearchResult::new();
+    // the heap holds indexes into `states` so that S doesn't have to be Ord
+    let mut states = vec![];
+    let mut heap = BinaryHeap::new();
+
+    for start in starts {
+        if result.distances.insert(start.clone(), 0).is_none() {
+            heap.push(Reverse((heuristic(&start), 0, states.len())));
+            states.push(start);
+        }
+    }
+
+    while let Some(Reverse((estimate, cost, i))) = heap.pop() {
+        if result.goal_cost().is_some_and(|best| estimate > best) {
+            break;
+        }
+
+        let state = states[i].clone();
+        if cost > result.distances[&state] {
+            continue; // a cheaper way here was found after this was queued
+        }
+
+        if is_goal(&state) {
+            result.goals.push(state);
+            continue;
+        }
+
+        for (next, step_cost) in successors(&state) {
+            let next_cost = cost + step_cost;
+            if result.relax(&state, next.clone(), next_cost) {
+                heap.push(Reverse((
+                    next_cost + heuristic(&next),
+                    next_cost,
+                    states.len(),
+                )));
+                states.push(next);
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::collections::HashSet;
+
+    // 0 -1-> 1 -1-> 3
+    // 0 -2-> 2 -0-> 3
+    // 0 -5-> 3
+    fn successors(n: &u8) -> Vec<(u8, usize)> {
+        match n {
+            0 => vec![(1, 1), (2, 2), (3, 5)],
+            1 => vec![(3, 1)],
+            2 => vec![(3, 0)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_cheapest_cost() {
+        let result = dijkstra([0], successors, |n| *n == 3);
+
+        assert_eq!(result.goal_cost(), Some(2));
+        assert_eq!(result.path(), Some(vec![0, 1, 3]));
+    }
+
+    #[test]
+    fn test_all_predecessors_at_the_same_cost() {
+        let result = dijkstra([0], successors, |n| *n == 3);
+
+        assert_eq!(result.predecessors(&3), &[1, 2]);
+        assert_eq!(result.on_optimal_paths().len(), 4);
+    }
+
+    #[test]
+    fn test_unreachable_goal() {
+        let result = dijkstra([1], successors, |n| *n == 0);
+
+        assert_eq!(result.goal_cost(), None);
+        assert_eq!(result.path(), None);
+        assert_eq!(result.distance(&3), Some(1));
+    }
+
+    #[test]
+    fn test_zero_cost_cycles() {
+        // 0 and 1 lead to each other for free, 1 loops on itself for free,
+        // and 2 is a start that 0 also reaches for free
+        let result = dijkstra(
+            [0u8, 2],
+            |n| match n {
+                0 => vec![(1, 0), (2, 0)],
+                1 => vec![(0, 0), (1, 0), (3, 1)],
+                _ => vec![],
+            },
+            |n| *n == 3,
+        );
+
+        assert_eq!(result.goal_cost(), Some(1));
+        assert_eq!(result.path(), Some(vec![0, 1, 3]));
+        assert_eq!(result.predecessors(&0), &[] as &[u8]);
+        assert_eq!(result.predecessors(&1), &[0]);
+        assert_eq!(result.predecessors(&2), &[] as &[u8]);
+        assert_eq!(result.on_optimal_paths().len(), 3);
+    }
+
+    #[test]
+    fn test_zero_cost_ties_keep_every_predecessor() {
+        // 1 is settled before 2, but 0 -> 2 -> 1 -> 3 is just as cheap
+        let result = dijkstra(
+            [0u8],
+            |n| match n {
+                0 => vec![(1, 0), (2, 0)],
+                2 => vec![(1, 0)],
+                1 => vec![(3, 1)],
+                _ => vec![],
+            },
+            |n| *n == 3,
+        );
+
+        assert_eq!(result.goal_cost(), Some(1));
+        assert_eq!(result.predecessors(&1), &[0, 2]);
+        assert_eq!(result.on_optimal_paths(), HashSet::from([0, 1, 2, 3]));
+    }
+
+    #[test]
+    fn test_astar_on_a_line() {
+        let result = astar(
+            [0i32],
+            |n| [(n - 1, 1), (n + 1, 1)],
+            |n| 10i32.abs_diff(*n) as usize,
+            |n| *n == 10,
+        );
+
+        assert_eq!(result.goal_cost(), Some(10));
+        assert_eq!(result.path(), Some((0..=10).collect()));
+        // the heuristic keeps the search from wandering into the negatives
+        assert_eq!(result.distance(&-2), None);
+    }
+}
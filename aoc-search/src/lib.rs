@@ -0,0 +1,7 @@
+mod bfs;
+mod dijkstra;
+mod search_result;
+
+pub use bfs::bfs;
+pub use dijkstra::{astar, dijkstra};
+pub use search_result::SearchResult;
@@ -0,0 +1,142 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+/// The outcome of a search: the cheapest known cost of every state that was
+/// settled, and for each of them every predecessor that reaches it at that
+/// cost. Together the predecessors form a DAG of all optimal paths.
+#[derive(Clone, Debug)]
+pub struct SearchResult<S> {
+    pub(crate) distances: HashMap<S, usize>,
+    pub(crate) predecessors: HashMap<S, Vec<S>>,
+    pub(crate) goals: Vec<S>,
+}
+
+impl<S: Clone + Eq + Hash> SearchResult<S> {
+    pub(crate) fn new() -> SearchResult<S> {
+        SearchResult {
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+            goals: vec![],
+        }
+    }
+
+    pub fn distance(&self, state: &S) -> Option<usize> {
+        self.distances.get(state).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<S, usize> {
+        &self.distances
+    }
+
+    /// every state that reaches `state` along an optimal path, empty for the
+    /// start states and for states that were never reached
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.predecessors.get(state).map_or(&[], |p| p.as_slice())
+    }
+
+    /// the goal states that were reached at the lowest cost, in the order
+    /// they were found
+    pub fn goals(&self) -> &[S] {
+        &self.goals
+    }
+
+    pub fn goal_cost(&self) -> Option<usize> {
+        self.distance(self.goals.first()?)
+    }
+
+    /// one optimal path from a start state to the first goal, inclusive of both
+    pub fn path(&self) -> Option<Vec<S>> {
+        self.path_to(self.goals.first()?)
+    }
+
+    /// one optimal path from a start state to `state`, inclusive of both
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        self.distances.get(state)?;
+
+        let mut path = vec![state.clone()];
+        while let Some(previous) = self.predecessors(path.last().unwrap()).first() {
+            path.push(previous.clone());
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    /// every state that lies on any optimal path to any of the goals
+    pub fn on_optimal_paths(&self) -> HashSet<S> {
+        self.on_optimal_paths_to(self.goals.iter().cloned())
+    }
+
+    /// every state that lies on any optimal path to any of `targets`
+    pub fn on_optimal_paths_to(&self, targets: impl IntoIterator<Item = S>) -> HashSet<S> {
+        let mut seen = HashSet::new();
+        let mut stack: Vec<S> = targets
+            .into_iter()
+            .filter(|s| self.distances.contains_key(s))
+            .collect();
+
+        while let Some(state) = stack.pop() {
+            if seen.contains(&state) {
+                continue;
+            }
+
+            stack.extend(self.predecessors(&state).iter().cloned());
+            seen.insert(state);
+        }
+
+        seen
+    }
+
+    /// whether `state` is `descendant` or on one of its optimal paths. Only a
+    /// zero-cost edge can lead back to an ancestor, so the walk never leaves
+    /// states at the same distance as `descendant`.
+    fn is_ancestor(&self, state: &S, descendant: &S) -> bool {
+        let distance = self.distance(descendant);
+        if distance.is_none() || self.distance(state) != distance {
+            return false;
+        }
+        let mut seen = HashSet::new();
+        let mut stack = vec![descendant];
+
+        while let Some(current) = stack.pop() {
+            if current == state {
+                return true;
+            }
+            if seen.insert(current) {
+                stack.extend(
+                    self.predecessors(current)
+                        .iter()
+                        .filter(|p| self.distance(p) == distance),
+                );
+            }
+        }
+
+        false
+    }
+
+    /// records that `state` can be reached from `previous` at `cost`. Returns
+    /// true when this is a new best cost for `state`. Start states never get
+    /// a predecessor, and neither does an ancestor of `previous`, since that
+    /// would close a loop of zero-cost edges.
+    pub(crate) fn relax(&mut self, previous: &S, state: S, cost: usize) -> bool {
+        match self.distances.get(&state) {
+            Some(&known) if known < cost => false,
+            // only start states have a distance without any predecessors
+            Some(&known) if known == cost && !self.predecessors.contains_key(&state) => false,
+            Some(&known) if known == cost => {
+                if !self.is_ancestor(&state, previous) {
+                    self.predecessors
+                        .entry(state)
+                        .or_default()
+                        .push(previous.clone());
+                }
+                false
+            }
+            _ => {
+                self.distances.insert(state.clone(), cost);
+                self.predecessors.insert(state, vec![previous.clone()]);
+                true
+            }
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use std::error::Error;
+
+/// A single day of the advent calendar. Each part receives the raw puzzle
+/// input and returns the answer formatted the way the puzzle expects it to be
+/// submitted.
+pub trait Solution {
+    fn part_1(&self, input: &str) -> Result<String, Box<dyn Error>>;
+    fn part_2(&self, input: &str) -> Result<String, Box<dyn Error>>;
+}
@@ -0,0 +1,25 @@
+//! Criterion benchmarks of every part against its checked in input.
+//!
+//! Some parts take minutes, so narrow the run with a filter, e.g.
+//! `cargo bench -p aoc -- day07/part_2`.
+
+use aoc::days;
+use criterion::{Criterion, criterion_group, criterion_main};
+use std::fs::read_to_string;
+use std::hint::black_box;
+
+fn bench_days(c: &mut Criterion) {
+    for day in days::DAYS {
+        let solution = days::solution(day).unwrap();
+        let input = read_to_string(days::default_input_path(day)).unwrap();
+
+        let mut group = c.benchmark_group(format!("day{day:02}"));
+        group.sample_size(10);
+        group.bench_function("part_1", |b| b.iter(|| solution.part_1(black_box(&input))));
+        group.bench_function("part_2", |b| b.iter(|| solution.part_2(black_box(&input))));
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_days);
+criterion_main!(benches);
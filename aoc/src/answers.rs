@@ -0,0 +1,76 @@
+use std::collections::BTreeMap;
+use std::fs::read_to_string;
+use std::io;
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+use crate::days::{self, day_key, part_key};
+
+/// The accepted answers from answers.toml, keyed like `[day07] part_2 = "..."`
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers(BTreeMap<String, BTreeMap<String, String>>);
+
+#[derive(Debug, Error)]
+pub enum AnswersError {
+    #[error("Failed to read answers {0}: {1}")]
+    Read(PathBuf, io::Error),
+
+    #[error("Failed to parse answers {0}: {1}")]
+    Parse(PathBuf, toml::de::Error),
+}
+
+impl Answers {
+    /// loads the answers checked in at the workspace root
+    pub fn load() -> Result<Answers, AnswersError> {
+        let path = answers_path();
+        let s = read_to_string(&path).map_err(|e| AnswersError::Read(path.clone(), e))?;
+
+        s.parse().map_err(|e| AnswersError::Parse(path, e))
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.0
+            .get(&day_key(day))?
+            .get(&part_key(part))
+            .map(|answer| answer.as_str())
+    }
+}
+
+impl std::str::FromStr for Answers {
+    type Err = toml::de::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Answers(toml::from_str(s)?))
+    }
+}
+
+pub fn answers_path() -> PathBuf {
+    days::workspace_root().join("answers.toml")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_get() {
+        let answers: Answers = "
+            [day17]
+            part_1 = \"7,4,2\"
+        "
+        .parse()
+        .unwrap();
+
+        assert_eq!(answers.get(17, 1), Some("7,4,2"));
+        assert_eq!(answers.get(17, 2), None);
+        assert_eq!(answers.get(1, 1), None);
+    }
+
+    #[test]
+    fn test_checked_in_answers_parse() {
+        let answers = Answers::load().unwrap();
+
+        assert_eq!(answers.get(1, 1), Some("2970687"));
+    }
+}
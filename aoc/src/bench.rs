@@ -0,0 +1,181 @@
+use std::collections::BTreeMap;
+use std::fs::{read_to_string, write};
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use aoc::days::{self, day_key, part_key};
+
+use crate::AocError::{self, *};
+use crate::{read_input, solve};
+
+/// every part is re-run until this much time has been spent on it, or it has
+/// been run `MAX_RUNS` times
+const MAX_RUNS: usize = 100;
+
+/// median nanoseconds per part, keyed like `[day06] part_1 = 481550000`
+type Baseline = BTreeMap<String, BTreeMap<String, u64>>;
+
+const BASELINE_HEADER: &str = "\
+# Median wall clock time of every part in nanoseconds, as measured by
+# `aoc bench --save` on a release build. `aoc bench` flags any part that has
+# become slower than this by more than its tolerance. Day 6 part 2 is left
+# out, see answers.toml for why.
+";
+
+pub(crate) struct BenchOptions {
+    pub(crate) budget: Duration,
+    pub(crate) tolerance: f64,
+    pub(crate) save: bool,
+}
+
+struct Measurement {
+    median: Duration,
+    runs: usize,
+}
+
+pub(crate) fn bench(days: &[u8], parts: &[u8], options: BenchOptions) -> Result<(), AocError> {
+    let path = baseline_path();
+    let mut baseline = load_baseline(&path)?;
+    let mut regressions = 0;
+    let mut failures = 0;
+
+    println!(
+        "{:>3} {:>4} {:>12} {:>5} {:>12} {:>8}",
+        "day", "part", "median", "runs", "baseline", "change"
+    );
+
+    for &day in days {
+        let input = read_input(&days::default_input_path(day))?;
+
+        for &part in parts {
+            let measurement = match measure(day, part, &input, options.budget) {
+                Ok(measurement) => measurement,
+                Err(e) => {
+                    println!("{:>3} {:>4} {e}", format!("{day:02}"), part);
+                    failures += 1;
+                    continue;
+                }
+            };
+            let previous = baseline
+                .get(&day_key(day))
+                .and_then(|parts| parts.get(&part_key(part)))
+                .map(|nanos| Duration::from_nanos(*nanos));
+
+            let (baseline_column, change_column) = match previous {
+                Some(previous) => {
+                    let change = percent_change(previous, measurement.median);
+                    let flag = match change > options.tolerance {
+                        true => {
+                            regressions += 1;
+                            "  REGRESSION"
+                        }
+                        false => "",
+                    };
+                    (format!("{previous:.2?}"), format!("{change:+.1}%{flag}"))
+                }
+                None => ("-".to_string(), "".to_string()),
+            };
+
+            println!(
+                "{:>3} {:>4} {:>12} {:>5} {:>12} {:>8}",
+                format!("{day:02}"),
+                part,
+                format!("{:.2?}", measurement.median),
+                measurement.runs,
+                baseline_column,
+                change_column
+            );
+
+            if options.save {
+                baseline
+                    .entry(day_key(day))
+                    .or_default()
+                    .insert(part_key(part), measurement.median.as_nanos() as u64);
+            }
+        }
+    }
+
+    if options.save {
+        save_baseline(&path, &baseline)?;
+        println!("saved baseline to {}", path.display());
+    }
+
+    match (failures, regressions) {
+        (0, 0) => Ok(()),
+        (0, _) if options.save => Ok(()),
+        (0, n) => Err(Regressions(n)),
+        (n, _) => Err(Failures(n)),
+    }
+}
+
+fn measure(day: u8, part: u8, input: &str, budget: Duration) -> Result<Measurement, AocError> {
+    let mut timings = vec![];
+    let mut total = Duration::ZERO;
+
+    while timings.is_empty() || (total < budget && timings.len() < MAX_RUNS) {
+        let (_answer, elapsed) = solve(day, part, input)?;
+        total += elapsed;
+        timings.push(elapsed);
+    }
+
+    timings.sort();
+
+    Ok(Measurement {
+        median: timings[timings.len() / 2],
+        runs: timings.len(),
+    })
+}
+
+fn percent_change(before: Duration, after: Duration) -> f64 {
+    let before = before.as_secs_f64().max(f64::EPSILON);
+    (after.as_secs_f64() - before) / before * 100.0
+}
+
+fn baseline_path() -> PathBuf {
+    days::workspace_root().join("bench_baseline.toml")
+}
+
+fn load_baseline(path: &PathBuf) -> Result<Baseline, AocError> {
+    match read_to_string(path) {
+        Ok(s) => toml::from_str(&s).map_err(|e| ParseBaseline(path.clone(), e)),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(Baseline::new()),
+        Err(e) => Err(ReadBaseline(path.clone(), e)),
+    }
+}
+
+fn save_baseline(path: &PathBuf, baseline: &Baseline) -> Result<(), AocError> {
+    let body = toml::to_string(baseline).expect("a map of maps of integers is valid toml");
+    write(path, format!("{BASELINE_HEADER}\n{body}")).map_err(|e| WriteBaseline(path.clone(), e))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_percent_change() {
+        let before = Duration::from_millis(100);
+        assert_eq!(
+            percent_change(before, Duration::from_millis(150)).round(),
+            50.0
+        );
+        assert_eq!(
+            percent_change(before, Duration::from_millis(50)).round(),
+            -50.0
+        );
+    }
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let baseline = Baseline::from([(
+            day_key(6),
+            BTreeMap::from([(part_key(1), 481_550_000), (part_key(2), 12)]),
+        )]);
+
+        let s = format!("{BASELINE_HEADER}\n{}", toml::to_string(&baseline).unwrap());
+        let result: Baseline = toml::from_str(&s).unwrap();
+
+        assert_eq!(result, baseline);
+    }
+}
@@ -0,0 +1,82 @@
+use clap::Subcommand;
+use day13::Rules;
+
+use crate::{AocError, InputArg, read_day_input, tool_error};
+
+#[derive(Debug, Subcommand)]
+pub(crate) enum Day13Command {
+    /// Print how many times to press each button to win every machine's
+    /// prize as cheaply as possible, then the tokens for all of them
+    Presses {
+        #[command(flatten)]
+        input: InputArg,
+
+        #[arg(long, default_value_t = 3)]
+        a_cost: usize,
+
+        #[arg(long, default_value_t = 1)]
+        b_cost: usize,
+
+        /// The most times either button may be pressed, unlimited if not
+        /// given
+        #[arg(long)]
+        max_presses: Option<usize>,
+
+        /// Move every prize this much further along both axes, like part 2
+        /// does by 10000000000000
+        #[arg(long, default_value_t = 0)]
+        offset: usize,
+    },
+}
+
+pub(crate) fn day13(command: Day13Command) -> Result<(), AocError> {
+    match command {
+        Day13Command::Presses {
+            input,
+            a_cost,
+            b_cost,
+            max_presses,
+            offset,
+        } => {
+            let rules = Rules {
+                a_cost,
+                b_cost,
+                max_presses,
+            };
+
+            let input = read_day_input(13, input)?;
+            let machines =
+                day13::cheapest_presses(&input, &rules, offset).map_err(|e| tool_error(13, e))?;
+            for (i, presses) in machines.iter().enumerate() {
+                match presses {
+                    None => println!("machine {}: can't be won", i + 1),
+                    Some(presses) => println!(
+                        "machine {}: A {} times, B {} times, {} tokens",
+                        i + 1,
+                        presses.a,
+                        presses.b,
+                        presses.tokens
+                    ),
+                }
+            }
+
+            let won: Vec<_> = machines.iter().flatten().collect();
+            let tokens = won
+                .iter()
+                .try_fold(0usize, |total, presses| total.checked_add(presses.tokens));
+            match tokens {
+                Some(tokens) => println!(
+                    "{} of {} prizes for {tokens} tokens",
+                    won.len(),
+                    machines.len()
+                ),
+                None => println!(
+                    "{} of {} prizes for more tokens than fit in a usize",
+                    won.len(),
+                    machines.len()
+                ),
+            }
+            Ok(())
+        }
+    }
+}
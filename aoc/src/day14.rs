@@ -0,0 +1,131 @@
+use std::path::PathBuf;
+use std::{fs, io};
+
+use clap::Subcommand;
+use day14::{ImageFormat, ImageOptions, OnDivider, Partition};
+
+use crate::{AocError, InputArg, read_day_input, tool_error};
+
+#[derive(Debug, Subcommand)]
+pub(crate) enum Day14Command {
+    /// Draw the robots at one second, or at a range of seconds as a contact
+    /// sheet, as a PBM, PGM or PNG image
+    Image {
+        /// Where to write the image, its extension picks the format unless
+        /// --format is given
+        output: PathBuf,
+
+        #[command(flatten)]
+        input: InputArg,
+
+        /// pbm, pgm or png
+        #[arg(short, long)]
+        format: Option<ImageFormat>,
+
+        /// The first second to draw
+        #[arg(long, default_value_t = 0)]
+        from: usize,
+
+        /// Draw every second up to, but not including, this one instead of
+        /// just the first
+        #[arg(long)]
+        to: Option<usize>,
+
+        /// Frames per row of the contact sheet
+        #[arg(long)]
+        columns: Option<usize>,
+
+        #[arg(long, default_value_t = 101)]
+        width: isize,
+
+        #[arg(long, default_value_t = 103)]
+        height: isize,
+    },
+
+    /// Split the map into a grid of cells and print how many robots are in
+    /// each one, then their safety factor
+    Cells {
+        #[command(flatten)]
+        input: InputArg,
+
+        /// The second to count the robots at
+        #[arg(short, long, default_value_t = 100)]
+        t: usize,
+
+        #[arg(long, default_value_t = 2)]
+        columns: usize,
+
+        #[arg(long, default_value_t = 2)]
+        rows: usize,
+
+        /// Where robots exactly on a divider go: skip, before or after
+        #[arg(long, default_value = "skip")]
+        on_divider: OnDivider,
+
+        #[arg(long, default_value_t = 101)]
+        width: isize,
+
+        #[arg(long, default_value_t = 103)]
+        height: isize,
+    },
+}
+
+pub(crate) fn day14(command: Day14Command) -> Result<(), AocError> {
+    match command {
+        Day14Command::Image {
+            output,
+            input,
+            format,
+            from,
+            to,
+            columns,
+            width,
+            height,
+        } => {
+            let format = match format {
+                Some(format) => format,
+                None => output
+                    .extension()
+                    .and_then(|extension| extension.to_str())
+                    .unwrap_or_default()
+                    .parse()
+                    .map_err(|e: String| tool_error(14, io::Error::other(e)))?,
+            };
+            let options = ImageOptions {
+                format,
+                bounds: (width, height),
+                frames: from..to.unwrap_or(from + 1),
+                columns,
+            };
+
+            let input = read_day_input(14, input)?;
+            let image = day14::image(&input, options).map_err(|e| tool_error(14, e))?;
+            fs::write(&output, image).map_err(|e| tool_error(14, e))
+        }
+        Day14Command::Cells {
+            input,
+            t,
+            columns,
+            rows,
+            on_divider,
+            width,
+            height,
+        } => {
+            let partition = Partition {
+                columns,
+                rows,
+                on_divider,
+            };
+
+            let input = read_day_input(14, input)?;
+            let counts = day14::cell_counts(&input, (width, height), t, partition)
+                .map_err(|e| tool_error(14, e))?;
+            print!("{counts}");
+            match counts.safety_factor() {
+                Some(factor) => println!("safety factor {factor}"),
+                None => println!("safety factor doesn't fit in a usize"),
+            }
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use std::fs;
+use std::path::PathBuf;
+
+use clap::{Args, Subcommand};
+use day15::PlaybackOptions;
+
+use crate::{AocError, InputArg, read_day_input, tool_error};
+
+#[derive(Debug, Subcommand)]
+pub(crate) enum Day15Command {
+    /// Run part 2 and find the first instruction after which a box or the
+    /// robot overlaps a wall or another box
+    Bisect {
+        #[command(flatten)]
+        input: InputArg,
+    },
+
+    /// Play part 2 back in the terminal, redrawing the map every step
+    Play {
+        #[command(flatten)]
+        input: InputArg,
+
+        #[command(flatten)]
+        playback: PlaybackArgs,
+    },
+
+    /// Play part 2's map by hand with the arrow keys or ^v<>, or pipe
+    /// instructions to stdin
+    Interactive {
+        #[command(flatten)]
+        input: InputArg,
+
+        /// Save the moves here as puzzle input, on `s` and when quitting
+        #[arg(short, long)]
+        save: Option<PathBuf>,
+    },
+
+    /// Record part 2's playback as an asciicast v2 file
+    Cast {
+        /// Where to write the .cast file
+        output: PathBuf,
+
+        #[command(flatten)]
+        input: InputArg,
+
+        #[command(flatten)]
+        playback: PlaybackArgs,
+    },
+}
+
+#[derive(Debug, Args)]
+pub(crate) struct PlaybackArgs {
+    /// Steps shown per second
+    #[arg(long, default_value_t = 30)]
+    fps: u32,
+
+    /// Start from this step
+    #[arg(long, default_value_t = 0)]
+    from: usize,
+
+    /// Stop at this step instead of the end of the run
+    #[arg(long)]
+    to: Option<usize>,
+}
+
+impl From<PlaybackArgs> for PlaybackOptions {
+    fn from(args: PlaybackArgs) -> Self {
+        PlaybackOptions {
+            fps: args.fps,
+            from: args.from,
+            to: args.to,
+        }
+    }
+}
+
+pub(crate) fn day15(command: Day15Command) -> Result<(), AocError> {
+    match command {
+        Day15Command::Bisect { input } => {
+            let input = read_day_input(15, input)?;
+            match day15::bisect(&input).map_err(|e| tool_error(15, e))? {
+                None => println!("The map stays valid through every instruction"),
+                Some(bisection) => {
+                    match bisection.instruction {
+                        None => println!("The map is invalid before any instruction"),
+                        Some(instruction) => println!(
+                            "The map is first invalid after instruction {} ({instruction})",
+                            bisection.step
+                        ),
+                    }
+                    print!("{}", bisection.map);
+                }
+            }
+            Ok(())
+        }
+        Day15Command::Play { input, playback } => {
+            let input = read_day_input(15, input)?;
+            day15::play(&input, playback.into()).map_err(|e| tool_error(15, e))
+        }
+        Day15Command::Interactive { input, save } => {
+            let input = read_day_input(15, input)?;
+            day15::interact(&input, save.as_deref()).map_err(|e| tool_error(15, e))
+        }
+        Day15Command::Cast {
+            output,
+            input,
+            playback,
+        } => {
+            let input = read_day_input(15, input)?;
+            let cast = day15::asciicast(&input, playback.into()).map_err(|e| tool_error(15, e))?;
+            fs::write(&output, cast).map_err(|e| tool_error(15, e))
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use std::io;
+use std::path::PathBuf;
+
+use clap::Subcommand;
+use day17::TraceFormat;
+
+use crate::{AocError, InputArg, read_day_input, read_input, tool_error};
+
+#[derive(Debug, Subcommand)]
+pub(crate) enum Day17Command {
+    /// Print the program as a listing of mnemonics
+    Disassemble {
+        #[command(flatten)]
+        input: InputArg,
+    },
+
+    /// Assemble a source listing of mnemonics into puzzle input
+    Assemble {
+        /// Source file with one instruction per line, labels like `loop:`
+        /// and `.a 729` directives for the initial registers
+        source: PathBuf,
+    },
+
+    /// Run the program and print every instruction it executed, with the
+    /// registers before and after and the output so far
+    Trace {
+        #[command(flatten)]
+        input: InputArg,
+
+        /// Start with this value in register A instead of the input's
+        #[arg(short)]
+        a: Option<usize>,
+
+        /// jsonl or csv
+        #[arg(short, long, default_value = "jsonl")]
+        format: TraceFormat,
+    },
+
+    /// Step through the program with breakpoints and watched registers,
+    /// reading commands from stdin (try `help`)
+    Debug {
+        #[command(flatten)]
+        input: InputArg,
+    },
+}
+
+pub(crate) fn day17(command: Day17Command) -> Result<(), AocError> {
+    match command {
+        Day17Command::Disassemble { input } => {
+            let input = read_day_input(17, input)?;
+            let listing = day17::disassemble(&input).map_err(|e| tool_error(17, e))?;
+            println!("{listing}");
+            Ok(())
+        }
+        Day17Command::Assemble { source } => {
+            let source = read_input(&source)?;
+            let input = day17::assemble(&source).map_err(|e| tool_error(17, e))?;
+            print!("{input}");
+            Ok(())
+        }
+        Day17Command::Trace { input, a, format } => {
+            let input = read_day_input(17, input)?;
+            let trace = day17::trace(&input, a, format).map_err(|e| tool_error(17, e))?;
+            print!("{trace}");
+            Ok(())
+        }
+        Day17Command::Debug { input } => {
+            let input = read_day_input(17, input)?;
+            day17::debug(&input, io::stdin().lock(), io::stdout()).map_err(|e| tool_error(17, e))
+        }
+    }
+}
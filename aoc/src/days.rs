@@ -0,0 +1,51 @@
+use aoc_solution::Solution;
+use std::path::{Path, PathBuf};
+
+pub const DAYS: std::ops::RangeInclusive<u8> = 1..=20;
+
+pub fn solution(day: u8) -> Option<&'static dyn Solution> {
+    match day {
+        1 => Some(&day01::Day01),
+        2 => Some(&day02::Day02),
+        3 => Some(&day03::Day03),
+        4 => Some(&day04::Day04),
+        5 => Some(&day05::Day05),
+        6 => Some(&day06::Day06),
+        7 => Some(&day07::Day07),
+        8 => Some(&day08::Day08),
+        9 => Some(&day09::Day09),
+        10 => Some(&day10::Day10),
+        11 => Some(&day11::Day11),
+        12 => Some(&day12::Day12),
+        13 => Some(&day13::Day13),
+        14 => Some(&day14::Day14),
+        15 => Some(&day15::Day15),
+        16 => Some(&day16::Day16),
+        17 => Some(&day17::Day17),
+        18 => Some(&day18::Day18),
+        19 => Some(&day19::Day19),
+        20 => Some(&day20::Day20),
+        _ => None,
+    }
+}
+
+/// the checked in puzzle input for a day, e.g. `day14/input.txt`
+pub fn default_input_path(day: u8) -> PathBuf {
+    workspace_root()
+        .join(format!("day{:02}", day))
+        .join("input.txt")
+}
+
+pub fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+/// the table name a day is stored under in answers.toml & bench_baseline.toml
+pub fn day_key(day: u8) -> String {
+    format!("day{day:02}")
+}
+
+/// the key a part is stored under in answers.toml & bench_baseline.toml
+pub fn part_key(part: u8) -> String {
+    format!("part_{part}")
+}
@@ -0,0 +1,5 @@
+//! The days, their checked in inputs and their known answers, shared by the
+//! `aoc` binary, the criterion benchmarks and the answer tests.
+
+pub mod answers;
+pub mod days;
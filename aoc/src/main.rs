@@ -0,0 +1,257 @@
+mod bench;
+mod day13;
+mod day14;
+mod day15;
+mod day17;
+mod verify;
+
+use aoc::answers::AnswersError;
+use aoc::days;
+use bench::BenchOptions;
+use clap::{Args, Parser, Subcommand};
+use day13::Day13Command;
+use day14::Day14Command;
+use day15::Day15Command;
+use day17::Day17Command;
+use std::{
+    error::Error,
+    fs::read_to_string,
+    io,
+    path::PathBuf,
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+use thiserror::Error;
+
+#[derive(Debug, Parser)]
+#[command(about = "Runs the Advent of Code 2024 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Solve a day (or every day if none is given) and print the answers
+    Run {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+
+        /// Only solve this part
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Puzzle input to use instead of the day's checked in input.txt
+        #[arg(short, long, requires = "day")]
+        input: Option<PathBuf>,
+    },
+
+    /// Time every part against its checked in input and compare the results
+    /// with bench_baseline.toml
+    Bench {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+
+        /// Only time this part
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Keep re-running a part until this many milliseconds have been
+        /// spent on it (it always runs at least once, at most 100 times)
+        #[arg(long, default_value_t = 1000)]
+        budget_ms: u64,
+
+        /// How many percent slower than the baseline a part may get before
+        /// it is flagged as a regression
+        #[arg(long, default_value_t = 25.0)]
+        tolerance: f64,
+
+        /// Record the measurements in the baseline instead of comparing
+        #[arg(long)]
+        save: bool,
+    },
+
+    /// Solve every part against its checked in input and check the answers
+    /// match the ones recorded in answers.toml
+    Verify {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+
+        /// Only verify this part
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+    },
+
+    /// Tools for day 13's claw machines
+    Day13 {
+        #[command(subcommand)]
+        command: Day13Command,
+    },
+
+    /// Tools for looking at day 14's robots
+    Day14 {
+        #[command(subcommand)]
+        command: Day14Command,
+    },
+
+    /// Tools for inspecting day 15's warehouse runs
+    Day15 {
+        #[command(subcommand)]
+        command: Day15Command,
+    },
+
+    /// Tools for inspecting day 17's 3-bit computer programs
+    Day17 {
+        #[command(subcommand)]
+        command: Day17Command,
+    },
+}
+
+/// the `--input` of a day's tools
+#[derive(Debug, Args)]
+struct InputArg {
+    /// Puzzle input to use instead of the checked in input.txt
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+}
+
+#[derive(Debug, Error)]
+enum AocError {
+    #[error("There is no solution for day {0}")]
+    UnknownDay(u8),
+
+    #[error("Failed to read input {0}: {1}")]
+    ReadInput(PathBuf, io::Error),
+
+    #[error("Day {day} part {part} failed: {source}")]
+    Solve {
+        day: u8,
+        part: u8,
+        source: Box<dyn Error>,
+    },
+
+    #[error("Failed to read benchmark baseline {0}: {1}")]
+    ReadBaseline(PathBuf, io::Error),
+
+    #[error("Failed to parse benchmark baseline {0}: {1}")]
+    ParseBaseline(PathBuf, toml::de::Error),
+
+    #[error("Failed to write benchmark baseline {0}: {1}")]
+    WriteBaseline(PathBuf, io::Error),
+
+    #[error("{0} part(s) got slower than the baseline")]
+    Regressions(usize),
+
+    #[error("{0} part(s) failed")]
+    Failures(usize),
+
+    #[error(transparent)]
+    Answers(#[from] AnswersError),
+
+    #[error("{0} answer(s) did not match answers.toml")]
+    Mismatches(usize),
+
+    #[error("Day {day} failed: {source}")]
+    Tool { day: u8, source: Box<dyn Error> },
+}
+
+use AocError::*;
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Run { day, part, input } => run(day, part, input),
+        Command::Bench {
+            day,
+            part,
+            budget_ms,
+            tolerance,
+            save,
+        } => {
+            let options = BenchOptions {
+                budget: Duration::from_millis(budget_ms),
+                tolerance,
+                save,
+            };
+            bench::bench(&select_days(day), &select_parts(part), options)
+        }
+        Command::Verify { day, part } => verify::verify(&select_days(day), &select_parts(part)),
+        Command::Day13 { command } => day13::day13(command),
+        Command::Day14 { command } => day14::day14(command),
+        Command::Day15 { command } => day15::day15(command),
+        Command::Day17 { command } => day17::day17(command),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn select_days(day: Option<u8>) -> Vec<u8> {
+    match day {
+        Some(day) => vec![day],
+        None => days::DAYS.collect(),
+    }
+}
+
+fn select_parts(part: Option<u8>) -> Vec<u8> {
+    match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    }
+}
+
+fn run(day: Option<u8>, part: Option<u8>, input: Option<PathBuf>) -> Result<(), AocError> {
+    let parts = select_parts(part);
+
+    for day in select_days(day) {
+        let path = input
+            .clone()
+            .unwrap_or_else(|| days::default_input_path(day));
+        let input = read_input(&path)?;
+
+        println!("day {:02}", day);
+        for part in parts.iter() {
+            let (answer, elapsed) = solve(day, *part, &input)?;
+            println!("  part_{}: {} ({:.2?})", part, answer, elapsed);
+        }
+    }
+
+    Ok(())
+}
+
+fn read_input(path: &PathBuf) -> Result<String, AocError> {
+    read_to_string(path).map_err(|e| ReadInput(path.clone(), e))
+}
+
+/// the input a day's tool was given, or else the day's checked in input.txt
+fn read_day_input(day: u8, input: InputArg) -> Result<String, AocError> {
+    read_input(&input.input.unwrap_or_else(|| days::default_input_path(day)))
+}
+
+/// wraps anything a day's tool fails with
+fn tool_error(day: u8, e: impl Error + 'static) -> AocError {
+    Tool {
+        day,
+        source: Box::new(e),
+    }
+}
+
+/// solves one part, timing only the solution itself
+fn solve(day: u8, part: u8, input: &str) -> Result<(String, Duration), AocError> {
+    let solution = days::solution(day).ok_or(UnknownDay(day))?;
+
+    let start = Instant::now();
+    let answer = match part {
+        1 => solution.part_1(input),
+        _ => solution.part_2(input),
+    }
+    .map_err(|source| Solve { day, part, source })?;
+
+    Ok((answer, start.elapsed()))
+}
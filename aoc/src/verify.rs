@@ -0,0 +1,43 @@
+use aoc::answers::Answers;
+use aoc::days;
+
+use crate::AocError::{self, *};
+use crate::{read_input, solve};
+
+/// re-runs every selected part that has a recorded answer and reports the
+/// ones whose answer changed or that failed outright
+pub(crate) fn verify(days: &[u8], parts: &[u8]) -> Result<(), AocError> {
+    let answers = Answers::load()?;
+    let mut mismatches = 0;
+
+    for &day in days {
+        let input = read_input(&days::default_input_path(day))?;
+
+        println!("day {:02}", day);
+        for &part in parts {
+            let Some(expected) = answers.get(day, part) else {
+                println!("  part_{part}: skipped, no recorded answer");
+                continue;
+            };
+
+            match solve(day, part, &input) {
+                Ok((answer, elapsed)) if answer == expected => {
+                    println!("  part_{part}: ok ({elapsed:.2?})");
+                }
+                Ok((answer, _elapsed)) => {
+                    println!("  part_{part}: MISMATCH expected {expected} but got {answer}");
+                    mismatches += 1;
+                }
+                Err(e) => {
+                    println!("  part_{part}: FAILED {e}");
+                    mismatches += 1;
+                }
+            }
+        }
+    }
+
+    match mismatches {
+        0 => Ok(()),
+        n => Err(Mismatches(n)),
+    }
+}
@@ -0,0 +1,37 @@
+use aoc::answers::Answers;
+use aoc::days;
+use std::fs::read_to_string;
+
+/// Several parts take seconds or more, so this only runs on request:
+/// `cargo test --release -p aoc -- --ignored`
+#[ignore]
+#[test]
+fn test_every_recorded_answer() {
+    let answers = Answers::load().unwrap();
+    let mut mismatches = vec![];
+
+    for day in days::DAYS {
+        let solution = days::solution(day).unwrap();
+        let input = read_to_string(days::default_input_path(day)).unwrap();
+
+        for part in [1, 2] {
+            let Some(expected) = answers.get(day, part) else {
+                continue;
+            };
+
+            let answer = match part {
+                1 => solution.part_1(&input),
+                _ => solution.part_2(&input),
+            }
+            .map_err(|e| e.to_string());
+
+            if answer.as_deref() != Ok(expected) {
+                mismatches.push(format!(
+                    "day {day:02} part {part}: {answer:?} != {expected}"
+                ));
+            }
+        }
+    }
+
+    assert!(mismatches.is_empty(), "{}", mismatches.join("\n"));
+}
@@ -1,9 +1,16 @@
-use std::fs::read_to_string;
+use aoc_solution::Solution;
+use std::error::Error;
 
-fn main() {
-    let input = read_to_string("./input.txt").unwrap();
-    println!("part_1: {}", compute_part_1(&input));
-    println!("part_2: {}", compute_part_2(&input));
+pub struct Day01;
+
+impl Solution for Day01 {
+    fn part_1(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        Ok(compute_part_1(input).to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        Ok(compute_part_2(input).to_string())
+    }
 }
 
 fn compute_part_1(input: &str) -> i32 {
@@ -12,11 +19,11 @@ fn compute_part_1(input: &str) -> i32 {
     column_a.sort();
     column_b.sort();
 
-    return column_a
+    column_a
         .into_iter()
         .zip(column_b)
         .map(|(a, b)| (a - b).abs())
-        .sum();
+        .sum()
 }
 
 fn compute_part_2(input: &str) -> i32 {
@@ -1,10 +1,17 @@
-use std::{collections::HashSet, fs::read_to_string};
+use aoc_solution::Solution;
+use std::collections::HashSet;
+use std::error::Error;
 
-fn main() {
-    let input = read_to_string("./input.txt").unwrap();
+pub struct Day02;
 
-    println!("part_1: {}", part_1(&input));
-    println!("part_2: {}", part_2(&input));
+impl Solution for Day02 {
+    fn part_1(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        Ok(part_1(input).to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        Ok(part_2(input).to_string())
+    }
 }
 
 fn part_1(input: &str) -> usize {
@@ -13,7 +20,7 @@ fn part_1(input: &str) -> usize {
         .lines()
         .map(parse_line)
         .map(to_directions)
-        .filter(is_conservatively_safe)
+        .filter(|directions| is_conservatively_safe(directions))
         .count()
 }
 
@@ -22,21 +29,21 @@ fn part_2(input: &str) -> usize {
         .trim()
         .lines()
         .map(parse_line)
-        .filter(is_recklessly_safe)
+        .filter(|line| is_recklessly_safe(line))
         .count()
 }
 
-fn is_conservatively_safe(directions: &Vec<Direction>) -> bool {
+fn is_conservatively_safe(directions: &[Direction]) -> bool {
     let set: HashSet<&Direction> = directions.iter().collect();
 
-    return set.len() == 1 && !set.contains(&Direction::Unsafe);
+    set.len() == 1 && !set.contains(&Direction::Unsafe)
 }
 
 fn is_conservatively_safe_p(directions: Vec<Direction>) -> bool {
     is_conservatively_safe(&directions)
 }
 
-fn is_recklessly_safe(line: &Vec<isize>) -> bool {
+fn is_recklessly_safe(line: &[isize]) -> bool {
     let directions = to_directions(line.to_vec());
 
     if is_conservatively_safe(&directions) {
@@ -51,13 +58,13 @@ fn is_recklessly_safe(line: &Vec<isize>) -> bool {
         .any(is_conservatively_safe_p)
 }
 
-fn to_leave_one_out_combinations(line: &Vec<isize>) -> Vec<Vec<isize>> {
+fn to_leave_one_out_combinations(line: &[isize]) -> Vec<Vec<isize>> {
     line.iter()
         .enumerate()
         .map(|(i, _)| {
-            let mut combination = line.clone();
+            let mut combination = line.to_vec();
             combination.remove(i);
-            return combination;
+            combination
         })
         .collect()
 }
@@ -65,11 +72,11 @@ fn to_leave_one_out_combinations(line: &Vec<isize>) -> Vec<Vec<isize>> {
 fn to_directions(line: Vec<isize>) -> Vec<Direction> {
     line.windows(2)
         .map(|window| {
-            let a = window.iter().nth(0).unwrap();
-            let b = window.iter().nth(1).unwrap();
+            let a = window.first().unwrap();
+            let b = window.get(1).unwrap();
 
             match a - b {
-                d if -3 <= d && d < 0 => Direction::SafeDown,
+                d if (-3..0).contains(&d) => Direction::SafeDown,
                 d if 0 < d && d <= 3 => Direction::SafeUp,
                 _ => Direction::Unsafe,
             }
@@ -87,7 +94,6 @@ enum Direction {
 fn parse_line(line: &str) -> Vec<isize> {
     line.trim()
         .split(' ')
-        .into_iter()
         .map(|n| n.trim().parse().unwrap())
         .collect()
 }
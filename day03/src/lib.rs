@@ -1,10 +1,17 @@
+use aoc_solution::Solution;
 use regex::Regex;
-use std::fs::read_to_string;
+use std::error::Error;
 
-fn main() {
-    let input = read_to_string("./input.txt").unwrap();
-    println!("part_1: {}", part_1(&input));
-    println!("part_2: {}", part_2(&input));
+pub struct Day03;
+
+impl Solution for Day03 {
+    fn part_1(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        Ok(part_1(input).to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        Ok(part_2(input).to_string())
+    }
 }
 
 fn part_1(input: &str) -> usize {
@@ -37,9 +44,9 @@ enum Token {
     Dont,
 }
 
-const MUL_REGEX: &'static str = r"mul\(\d{1,3},\d{1,3}\)";
-const DO_REGEX: &'static str = r"do\(\)";
-const DONT_REGEX: &'static str = r"don't\(\)";
+const MUL_REGEX: &str = r"mul\(\d{1,3},\d{1,3}\)";
+const DO_REGEX: &str = r"do\(\)";
+const DONT_REGEX: &str = r"don't\(\)";
 
 fn extract_tokens(input: &str) -> Vec<Token> {
     Regex::new(format!("{}|{}|{}", MUL_REGEX, DO_REGEX, DONT_REGEX).as_str())
@@ -92,13 +99,13 @@ mod tests {
     #[test]
     fn test_part_1() {
         let input = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
-        assert_eq!(part_1(&input), 161);
+        assert_eq!(part_1(input), 161);
     }
 
     #[test]
     fn test_part_2() {
         let input = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
-        assert_eq!(part_2(&input), 48);
+        assert_eq!(part_2(input), 48);
     }
 
     #[test]
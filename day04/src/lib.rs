@@ -1,9 +1,16 @@
-use std::fs::read_to_string;
+use aoc_solution::Solution;
+use std::error::Error;
 
-fn main() {
-    let input = read_to_string("./input.txt").unwrap();
-    println!("part_1: {}", part_1(&input));
-    println!("part_2: {}", part_2(&input));
+pub struct Day04;
+
+impl Solution for Day04 {
+    fn part_1(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        Ok(part_1(input).to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        Ok(part_2(input).to_string())
+    }
 }
 
 fn part_1(input: &str) -> usize {
@@ -43,7 +50,7 @@ fn part_2(input: &str) -> usize {
         .sum()
 }
 
-fn count_xmas(lines: &Vec<Vec<char>>, x: isize, y: isize) -> usize {
+fn count_xmas(lines: &[Vec<char>], x: isize, y: isize) -> usize {
     xmas_n(lines, x, y)
         + xmas_ne(lines, x, y)
         + xmas_e(lines, x, y)
@@ -54,7 +61,7 @@ fn count_xmas(lines: &Vec<Vec<char>>, x: isize, y: isize) -> usize {
         + xmas_nw(lines, x, y)
 }
 
-fn count_x_mas(lines: &Vec<Vec<char>>, x: isize, y: isize) -> usize {
+fn count_x_mas(lines: &[Vec<char>], x: isize, y: isize) -> usize {
     let c = character_at(lines, x, y);
     let nw = character_at(lines, x - 1, y - 1);
     let ne = character_at(lines, x + 1, y - 1);
@@ -70,51 +77,51 @@ fn count_x_mas(lines: &Vec<Vec<char>>, x: isize, y: isize) -> usize {
     }
 }
 
-fn xmas_n(lines: &Vec<Vec<char>>, x: isize, y: isize) -> usize {
+fn xmas_n(lines: &[Vec<char>], x: isize, y: isize) -> usize {
     match_xmas(lines, [(x, y), (x, y - 1), (x, y - 2), (x, y - 3)])
 }
 
-fn xmas_ne(lines: &Vec<Vec<char>>, x: isize, y: isize) -> usize {
+fn xmas_ne(lines: &[Vec<char>], x: isize, y: isize) -> usize {
     match_xmas(
         lines,
         [(x, y), (x + 1, y - 1), (x + 2, y - 2), (x + 3, y - 3)],
     )
 }
 
-fn xmas_e(lines: &Vec<Vec<char>>, x: isize, y: isize) -> usize {
+fn xmas_e(lines: &[Vec<char>], x: isize, y: isize) -> usize {
     match_xmas(lines, [(x, y), (x + 1, y), (x + 2, y), (x + 3, y)])
 }
 
-fn xmas_se(lines: &Vec<Vec<char>>, x: isize, y: isize) -> usize {
+fn xmas_se(lines: &[Vec<char>], x: isize, y: isize) -> usize {
     match_xmas(
         lines,
         [(x, y), (x + 1, y + 1), (x + 2, y + 2), (x + 3, y + 3)],
     )
 }
 
-fn xmas_s(lines: &Vec<Vec<char>>, x: isize, y: isize) -> usize {
+fn xmas_s(lines: &[Vec<char>], x: isize, y: isize) -> usize {
     match_xmas(lines, [(x, y), (x, y + 1), (x, y + 2), (x, y + 3)])
 }
 
-fn xmas_sw(lines: &Vec<Vec<char>>, x: isize, y: isize) -> usize {
+fn xmas_sw(lines: &[Vec<char>], x: isize, y: isize) -> usize {
     match_xmas(
         lines,
         [(x, y), (x - 1, y + 1), (x - 2, y + 2), (x - 3, y + 3)],
     )
 }
 
-fn xmas_w(lines: &Vec<Vec<char>>, x: isize, y: isize) -> usize {
+fn xmas_w(lines: &[Vec<char>], x: isize, y: isize) -> usize {
     match_xmas(lines, [(x, y), (x - 1, y), (x - 2, y), (x - 3, y)])
 }
 
-fn xmas_nw(lines: &Vec<Vec<char>>, x: isize, y: isize) -> usize {
+fn xmas_nw(lines: &[Vec<char>], x: isize, y: isize) -> usize {
     match_xmas(
         lines,
         [(x, y), (x - 1, y - 1), (x - 2, y - 2), (x - 3, y - 3)],
     )
 }
 
-fn match_xmas(lines: &Vec<Vec<char>>, coords: [(isize, isize); 4]) -> usize {
+fn match_xmas(lines: &[Vec<char>], coords: [(isize, isize); 4]) -> usize {
     let first = character_at(lines, coords[0].0, coords[0].1);
     let second = character_at(lines, coords[1].0, coords[1].1);
     let third = character_at(lines, coords[2].0, coords[2].1);
@@ -128,7 +135,7 @@ fn match_xmas(lines: &Vec<Vec<char>>, coords: [(isize, isize); 4]) -> usize {
     }
 }
 
-fn character_at(lines: &Vec<Vec<char>>, x: isize, y: isize) -> char {
+fn character_at(lines: &[Vec<char>], x: isize, y: isize) -> char {
     if x < 0 || y < 0 {
         return '.';
     }
@@ -136,13 +143,7 @@ fn character_at(lines: &Vec<Vec<char>>, x: isize, y: isize) -> char {
     let x: usize = x.try_into().unwrap();
     let y: usize = y.try_into().unwrap();
 
-    *lines
-        .iter()
-        .nth(y)
-        .unwrap_or(&vec![])
-        .iter()
-        .nth(x)
-        .unwrap_or(&'.')
+    *lines.get(y).unwrap_or(&vec![]).get(x).unwrap_or(&'.')
 }
 
 #[cfg(test)]
@@ -163,7 +164,7 @@ mod tests {
             .A.A.A.A.A
             ..M.M.M.MM
             .X.X.XMASX";
-        assert_eq!(part_1(&input), 18);
+        assert_eq!(part_1(input), 18);
     }
 
     #[test]
@@ -179,24 +180,24 @@ mod tests {
             .A.A.A.A..
             M.M.M.M.M.
             ..........";
-        assert_eq!(part_2(&input), 9);
+        assert_eq!(part_2(input), 9);
     }
 
     #[test]
     fn test_part_1_when_empty() {
         let input = "";
-        assert_eq!(part_1(&input), 0);
+        assert_eq!(part_1(input), 0);
     }
 
     #[test]
     fn test_part_1_when_the_word() {
         let input = "XMAS";
-        assert_eq!(part_1(&input), 1);
+        assert_eq!(part_1(input), 1);
     }
 
     #[test]
     fn test_part_1_when_not_the_word() {
         let input = "XMA.";
-        assert_eq!(part_1(&input), 0);
+        assert_eq!(part_1(input), 0);
     }
 }
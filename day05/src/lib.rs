@@ -1,10 +1,17 @@
+use aoc_solution::Solution;
+use std::collections::HashSet;
 use std::error::Error;
-use std::{collections::HashSet, fs::read_to_string};
 
-fn main() {
-    let input = read_to_string("./input.txt").unwrap();
-    println!("part_1: {}", part_1(&input));
-    println!("part_2: {}", part_2(&input));
+pub struct Day05;
+
+impl Solution for Day05 {
+    fn part_1(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        Ok(part_1(input).to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        Ok(part_2(input).to_string())
+    }
 }
 
 fn part_1(input: &str) -> usize {
@@ -13,7 +20,7 @@ fn part_1(input: &str) -> usize {
     updates
         .iter()
         .filter(|update| is_valid_update(&rules, update))
-        .map(|update| middle_page(&update))
+        .map(|update| middle_page(update))
         .sum()
 }
 
@@ -23,22 +30,23 @@ fn part_2(input: &str) -> usize {
     updates
         .iter()
         .filter(|update| is_not_valid_update(&rules, update))
-        .map(|update| fix_update(&rules, &update))
+        .map(|update| fix_update(&rules, update))
         .map(|update| middle_page(&update))
         .sum()
 }
 
-fn middle_page(update: &Vec<usize>) -> usize {
+fn middle_page(update: &[usize]) -> usize {
     let length = update.len();
     assert_eq!(length % 2, 1, "Update length was not odd: {}", length);
     let i = length / 2;
 
-    *update.iter().nth(i).unwrap()
+    *update.get(i).unwrap()
 }
 
-fn parse_updates_and_rules(
-    input: &str,
-) -> Result<(Vec<(usize, usize)>, Vec<Vec<usize>>), Box<dyn Error>> {
+type Rule = (usize, usize);
+type Update = Vec<usize>;
+
+fn parse_updates_and_rules(input: &str) -> Result<(Vec<Rule>, Vec<Update>), Box<dyn Error>> {
     let (rules, updates) = input.split_once("\n\n").ok_or("Missing double newline")?;
     let rules = parse_rules(rules)?;
     let updates = parse_updates(updates)?;
@@ -69,17 +77,17 @@ fn parse_int(s: &str) -> Result<usize, Box<dyn Error>> {
     Ok(i)
 }
 
-fn is_not_valid_update(rules: &Vec<(usize, usize)>, update: &Vec<usize>) -> bool {
+fn is_not_valid_update(rules: &[(usize, usize)], update: &[usize]) -> bool {
     !is_valid_update(rules, update)
 }
 
-fn is_valid_update(rules: &Vec<(usize, usize)>, update: &Vec<usize>) -> bool {
+fn is_valid_update(rules: &[(usize, usize)], update: &[usize]) -> bool {
     update.iter().enumerate().all(|(i, page)| {
-        let preceding_pages: HashSet<usize> = update.iter().take(i).map(|p| p.clone()).collect();
+        let preceding_pages: HashSet<usize> = update.iter().take(i).copied().collect();
 
         let invalid_pages: HashSet<usize> = rules
             .iter()
-            .map(|rule| rule.clone())
+            .copied()
             .filter_map(|(x, y)| match x == *page {
                 true => Some(y),
                 false => None,
@@ -90,7 +98,7 @@ fn is_valid_update(rules: &Vec<(usize, usize)>, update: &Vec<usize>) -> bool {
     })
 }
 
-fn rules_is_valid_for_update(rule: &(usize, usize), update: &Vec<usize>) -> bool {
+fn rules_is_valid_for_update(rule: &(usize, usize), update: &[usize]) -> bool {
     let (a, b) = rule;
     let ai = update.iter().position(|p| p == a);
     let bi = update.iter().position(|p| p == b);
@@ -101,18 +109,18 @@ fn rules_is_valid_for_update(rule: &(usize, usize), update: &Vec<usize>) -> bool
     }
 }
 
-fn fix_update(rules: &Vec<(usize, usize)>, update: &Vec<usize>) -> Vec<usize> {
+fn fix_update(rules: &[(usize, usize)], update: &[usize]) -> Vec<usize> {
     let invalid_rule = rules
         .iter()
         .find(|rule| !rules_is_valid_for_update(rule, update));
 
     match invalid_rule {
-        None => update.clone(),
+        None => update.to_vec(),
         Some((a, b)) => {
             let ai = update.iter().position(|p| p == a).unwrap();
             let bi = update.iter().position(|p| p == b).unwrap();
 
-            let mut update = update.clone();
+            let mut update = update.to_vec();
             update.swap(ai, bi);
             fix_update(rules, &update)
         }
@@ -123,6 +131,7 @@ fn fix_update(rules: &Vec<(usize, usize)>, update: &Vec<usize>) -> Vec<usize> {
 mod tests {
     // Note this useful idiom: importing names from outer (for mod tests) scope.
     use super::*;
+    use std::fs::read_to_string;
 
     #[test]
     fn test_part_1() {
@@ -138,19 +147,19 @@ mod tests {
 
     #[test]
     fn test_valid_update_when_empty() {
-        let result = is_valid_update(&vec![], &vec![]);
+        let result = is_valid_update(&[], &[]);
         assert!(result)
     }
 
     #[test]
     fn test_valid_update_when_one_rule_one_update() {
-        let result = is_valid_update(&vec![(1, 2)], &vec![1, 2]);
+        let result = is_valid_update(&[(1, 2)], &[1, 2]);
         assert!(result)
     }
 
     #[test]
     fn test_valid_update_when_one_rule_one_invalid_update() {
-        let result = is_valid_update(&vec![(1, 2)], &vec![2, 1]);
+        let result = is_valid_update(&[(1, 2)], &[2, 1]);
         assert!(!result)
     }
 
@@ -158,9 +167,9 @@ mod tests {
     fn test_valid_update_example_update_1() {
         let input = read_to_string("./example_input.txt").unwrap();
         let (rules, updates) = parse_updates_and_rules(&input).unwrap();
-        let update = updates.iter().nth(0).unwrap();
+        let update = updates.first().unwrap();
 
-        let result = is_valid_update(&rules, &update);
+        let result = is_valid_update(&rules, update);
         assert!(result);
     }
 
@@ -168,9 +177,9 @@ mod tests {
     fn test_valid_update_example_update_4() {
         let input = read_to_string("./example_input.txt").unwrap();
         let (rules, updates) = parse_updates_and_rules(&input).unwrap();
-        let update = updates.iter().nth(3).unwrap();
+        let update = updates.get(3).unwrap();
 
-        let result = is_valid_update(&rules, &update);
+        let result = is_valid_update(&rules, update);
         assert!(!result);
     }
 
@@ -178,15 +187,15 @@ mod tests {
     fn test_valid_update_example_update_5() {
         let input = read_to_string("./example_input.txt").unwrap();
         let (rules, updates) = parse_updates_and_rules(&input).unwrap();
-        let update = updates.iter().nth(3).unwrap();
+        let update = updates.get(3).unwrap();
 
-        let result = is_valid_update(&rules, &update);
+        let result = is_valid_update(&rules, update);
         assert!(!result);
     }
 
     #[test]
     fn test_middle_page() {
-        let result = middle_page(&vec![1, 2, 3]);
+        let result = middle_page(&[1, 2, 3]);
         assert_eq!(result, 2)
     }
 
@@ -1,11 +1,18 @@
+use aoc_solution::Solution;
 use rayon::prelude::*;
-use std::{collections::HashSet, fs::read_to_string};
+use std::collections::HashSet;
+use std::error::Error;
 
-fn main() {
-    let input = read_to_string("./input.txt").unwrap();
+pub struct Day06;
 
-    println!("part_1: {}", part_1(&input));
-    println!("part_2: {}", part_2(&input));
+impl Solution for Day06 {
+    fn part_1(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        Ok(part_1(input).to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        Ok(part_2(input).to_string())
+    }
 }
 
 fn part_1(input: &str) -> usize {
@@ -24,7 +31,7 @@ fn part_2(input: &str) -> usize {
 }
 
 fn is_loop(input: &str, trail: &HashSet<Guard>, guard: &Guard) -> bool {
-    match step(input, &guard) {
+    match step(input, guard) {
         // we have stepped out of bounds, so no loop
         None => false,
         // we are in bounds, let's see if we've been here before
@@ -35,7 +42,7 @@ fn is_loop(input: &str, trail: &HashSet<Guard>, guard: &Guard) -> bool {
             false => {
                 let mut next_trail = trail.clone();
                 next_trail.insert(next_guard.clone());
-                is_loop(&input, &next_trail, &next_guard)
+                is_loop(input, &next_trail, &next_guard)
             }
         },
     }
@@ -76,8 +83,8 @@ fn loop_to_string(input: &str, trail: &HashSet<Guard>, guard: &Guard) -> String
 //     that it performed in the past. This is defined as their current
 //     direction and position is already present in the trail
 fn find_obstacles(input: &str) -> HashSet<(usize, usize)> {
-    let og_guard = find_guard(&input).unwrap();
-    let trail = recursively_step(&input, &HashSet::new(), &og_guard);
+    let og_guard = find_guard(input).unwrap();
+    let trail = recursively_step(input, &HashSet::new(), &og_guard);
     let in_front_of_guard = og_guard.advance().unwrap();
 
     let mut trail = trail.clone();
@@ -93,7 +100,7 @@ fn find_obstacles(input: &str) -> HashSet<(usize, usize)> {
                 return None;
             }
 
-            match is_loop(&insert_obstacle(&input, &pos), &HashSet::new(), &og_guard) {
+            match is_loop(&insert_obstacle(input, &pos), &HashSet::new(), &og_guard) {
                 false => None,
                 true => Some(pos),
             }
@@ -107,14 +114,14 @@ fn insert_obstacle(input: &str, pos: &(usize, usize)) -> String {
 
 fn insert_char_at(input: &str, pos: &(usize, usize), n: char) -> String {
     let (x, y) = pos;
-    let row_length = input.lines().nth(0).unwrap().len() + 1; // plus 1 for the newline
+    let row_length = input.lines().next().unwrap().len() + 1; // plus 1 for the newline
     let mut output = input.to_string();
     let i = (y * row_length) + x;
     let range = i..i + 1;
     output.replace_range(range, &n.to_string());
 
     assert_ne!(input, output, "Input and output should not be the same");
-    return output;
+    output
 }
 
 fn trim_lines(input: &str) -> String {
@@ -127,12 +134,12 @@ fn trim_lines(input: &str) -> String {
 }
 
 fn recursively_step(input: &str, trail: &HashSet<Guard>, guard: &Guard) -> HashSet<Guard> {
-    match step(input, &guard) {
+    match step(input, guard) {
         None => trail.clone(),
         Some(next_guard) => {
             let mut next_trail = trail.clone();
             next_trail.insert(next_guard.clone());
-            recursively_step(&input, &next_trail, &next_guard)
+            recursively_step(input, &next_trail, &next_guard)
         }
     }
 }
@@ -181,8 +188,8 @@ impl Guard {
 
     fn advance(&self) -> Option<Guard> {
         match self.clone() {
-            Guard::North((_, y)) if y == 0 => return None,
-            Guard::West((x, _)) if x == 0 => return None,
+            Guard::North((_, 0)) => return None,
+            Guard::West((0, _)) => return None,
             _ => {}
         }
 
@@ -236,7 +243,7 @@ mod tests {
             #.........
             ......#...";
 
-        assert_eq!(part_1(&input), 41);
+        assert_eq!(part_1(input), 41);
     }
 
     #[test]
@@ -253,7 +260,7 @@ mod tests {
             #.........
             ......#...";
 
-        assert_eq!(part_2(&input), 6);
+        assert_eq!(part_2(input), 6);
     }
 
     #[test]
@@ -1,16 +1,23 @@
 mod part_1;
 mod part_2;
 
+use aoc_solution::Solution;
 use part_1::is_solveable as part_1_is_solveable;
 use part_2::is_solveable as part_2_is_solveable;
-use std::{fs::read_to_string, num::ParseIntError, str::FromStr};
+use std::error::Error;
+use std::{num::ParseIntError, str::FromStr};
 use thiserror::Error;
 
-fn main() {
-    let input = read_to_string("./input.txt").unwrap();
+pub struct Day07;
 
-    println!("part_1: {}", part_1(&input).unwrap());
-    println!("part_2: {}", part_2(&input).unwrap());
+impl Solution for Day07 {
+    fn part_1(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        Ok(part_1(input)?.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        Ok(part_2(input)?.to_string())
+    }
 }
 
 fn part_1(input: &str) -> Result<usize, InputLineParseErr> {
@@ -74,6 +81,7 @@ impl FromStr for InputLine {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::fs::read_to_string;
 
     #[test]
     fn test_part_1_example() {
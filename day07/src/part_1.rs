@@ -1,8 +1,8 @@
-pub fn is_solveable(target: usize, values: &Vec<usize>) -> bool {
+pub fn is_solveable(target: usize, values: &[usize]) -> bool {
     walk(target, 0, values)
 }
 
-fn walk(target: usize, acc: usize, values: &Vec<usize>) -> bool {
+fn walk(target: usize, acc: usize, values: &[usize]) -> bool {
     if target < acc {
         return false;
     };
@@ -10,7 +10,7 @@ fn walk(target: usize, acc: usize, values: &Vec<usize>) -> bool {
     match values.split_first() {
         None => target == acc,
         Some((value, rest)) => {
-            let rest: Vec<_> = rest.into_iter().cloned().collect();
+            let rest: Vec<_> = rest.to_vec();
             walk(target, acc + value, &rest) || walk(target, acc * value, &rest)
         }
     }
@@ -22,25 +22,25 @@ mod tests {
 
     #[test]
     fn test_is_solveable_empty() {
-        let solveable = is_solveable(1, &vec![]);
+        let solveable = is_solveable(1, &[]);
         assert!(!solveable);
     }
 
     #[test]
     fn test_is_solveable_one_value() {
-        let solveable = is_solveable(1, &vec![1]);
+        let solveable = is_solveable(1, &[1]);
         assert!(solveable);
     }
 
     #[test]
     fn test_is_solveable_one_value_not_solveable() {
-        let solveable = is_solveable(2, &vec![1]);
+        let solveable = is_solveable(2, &[1]);
         assert!(!solveable);
     }
 
     #[test]
     fn test_is_solveable_two_values_need_multiplication() {
-        let solveable = is_solveable(6, &vec![2, 3]);
+        let solveable = is_solveable(6, &[2, 3]);
         assert!(solveable);
     }
 }
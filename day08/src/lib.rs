@@ -0,0 +1,21 @@
+mod parse_input;
+mod part_1;
+mod part_2;
+mod tuple_tools;
+
+use aoc_solution::Solution;
+use part_1::part_1;
+use part_2::part_2;
+use std::error::Error;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    fn part_1(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        Ok(part_1(input).to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        Ok(part_2(input).to_string())
+    }
+}
@@ -16,7 +16,7 @@ pub fn dimensions(input: &str) -> (usize, usize) {
 
     match input.lines().count() {
         0 => (0, 0),
-        height => (input.lines().nth(0).unwrap().len(), height),
+        height => (input.lines().next().unwrap().len(), height),
     }
 }
 
@@ -5,13 +5,13 @@ use crate::tuple_tools::{i_to_u, u_to_i};
 use itertools::Itertools;
 
 pub fn part_2(input: &str) -> usize {
-    let (width, height) = dimensions(&input);
+    let (width, height) = dimensions(input);
     assert!(width > 0, "Width must be greater than 0");
     assert!(height > 0, "height must be greater than 0");
 
     let (max_x, max_y) = (width - 1, height - 1);
 
-    parse_input(&input)
+    parse_input(input)
         .values()
         .flat_map(|antennas| find_antinodes_for_all_antennas((max_x, max_y), antennas))
         .collect::<HashSet<(usize, usize)>>()
@@ -20,7 +20,7 @@ pub fn part_2(input: &str) -> usize {
 
 fn find_antinodes_for_all_antennas(
     (max_x, max_y): (usize, usize),
-    antennas: &Vec<(usize, usize)>,
+    antennas: &[(usize, usize)],
 ) -> HashSet<(usize, usize)> {
     antennas
         .iter()
@@ -101,19 +101,19 @@ mod test {
 
     #[test]
     fn test_find_antinodes_for_all_antennas_when_empty() {
-        let result = find_antinodes_for_all_antennas((0, 0), &vec![]);
+        let result = find_antinodes_for_all_antennas((0, 0), &[]);
         assert_eq!(result, HashSet::new())
     }
 
     #[test]
     fn test_find_antinodes_for_all_antennas_when_one() {
-        let result = find_antinodes_for_all_antennas((0, 0), &vec![(0, 0)]);
+        let result = find_antinodes_for_all_antennas((0, 0), &[(0, 0)]);
         assert_eq!(result, HashSet::new())
     }
 
     #[test]
     fn test_find_antinodes_for_all_antennas_when_two() {
-        let result = find_antinodes_for_all_antennas((3, 3), &vec![(1, 1), (2, 2)]);
+        let result = find_antinodes_for_all_antennas((3, 3), &[(1, 1), (2, 2)]);
         assert_eq!(result, HashSet::from([(0, 0), (1, 1), (2, 2), (3, 3)]))
     }
 
@@ -1,7 +1,7 @@
 use std::collections::VecDeque;
 
 #[allow(dead_code)]
-pub fn disk_to_string(v: &Vec<Option<usize>>) -> String {
+pub fn disk_to_string(v: &[Option<usize>]) -> String {
     v.iter()
         .map(|c| match c {
             Some(x) => format!("{}", x),
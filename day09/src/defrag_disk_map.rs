@@ -63,6 +63,7 @@ impl DiskMap {
     }
 
     // Returns a new defragmented DiskMap, consumes the original
+    #[allow(clippy::wrong_self_convention)]
     pub fn to_defragged(&mut self) -> DiskMap {
         let mut processed_ids: HashSet<usize> = HashSet::new();
 
@@ -78,12 +79,9 @@ impl DiskMap {
         }
 
         let last_used = self.last_used_block(&HashSet::new());
-        match last_used {
-            Some((id, _size)) => {
-                let i = self.index_of(id);
-                self.items.truncate(i + 1);
-            }
-            None => {}
+        if let Some((id, _size)) = last_used {
+            let i = self.index_of(id);
+            self.items.truncate(i + 1);
         }
 
         self.items.iter().cloned().collect()
@@ -167,7 +165,6 @@ impl FromStr for DiskMap {
             .map(|c| c.to_string().parse::<usize>().unwrap())
             .collect::<Vec<usize>>()
             .chunks(2)
-            .into_iter()
             .enumerate()
             .map(parse_chunk)
             .collect::<DiskMap>())
@@ -179,7 +176,7 @@ fn parse_chunk((id, c): (usize, &[usize])) -> (usize, usize, Option<usize>) {
     let used = c.pop_front().unwrap();
     let free = match c.pop_front() {
         None => None,
-        Some(size) if size == 0 => None,
+        Some(0) => None,
         Some(size) => Some(size),
     };
 
@@ -229,7 +226,7 @@ fn chunk_to_block((id, used, free): Chunk) -> Vec<Block> {
 }
 
 #[allow(dead_code)]
-fn disk_to_string(v: &Vec<Block>) -> String {
+fn disk_to_string(v: &[Block]) -> String {
     v.iter()
         .flat_map(expand_block)
         .map(|c| match c {
@@ -1,15 +1,22 @@
 mod compact_disk_map;
 mod defrag_disk_map;
 
+use aoc_solution::Solution;
 use compact_disk_map::CompactDiskMap;
 use defrag_disk_map::DiskMap;
-use std::{collections::VecDeque, fs::read_to_string, vec};
+use std::error::Error;
+use std::{collections::VecDeque, vec};
 
-fn main() {
-    let input = read_to_string("./input.txt").unwrap();
+pub struct Day09;
 
-    println!("part_1: {}", part_1(&input));
-    println!("part_2: {}", part_2(&input));
+impl Solution for Day09 {
+    fn part_1(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        Ok(part_1(input).to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        Ok(part_2(input).to_string())
+    }
 }
 
 fn part_1(input: &str) -> usize {
@@ -19,7 +26,6 @@ fn part_1(input: &str) -> usize {
         .map(|c| c.to_string().parse::<usize>().unwrap())
         .collect::<Vec<usize>>()
         .chunks(2)
-        .into_iter()
         .enumerate()
         .map(parse_chunk)
         .flat_map(expand_chunk)
@@ -66,7 +72,7 @@ mod test {
     #[test]
     fn test_part_1_example() {
         let input = "2333133121414131402";
-        let result = part_1(&input);
+        let result = part_1(input);
 
         assert_eq!(result, 1928);
     }
@@ -74,7 +80,7 @@ mod test {
     #[test]
     fn test_part_2_example() {
         let input = "2333133121414131402";
-        let result = part_2(&input);
+        let result = part_2(input);
 
         assert_eq!(result, 2858);
     }
@@ -82,7 +88,7 @@ mod test {
     #[test]
     fn test_part_1_simple() {
         let input = "12345";
-        let result = part_1(&input);
+        let result = part_1(input);
         // expanded: "0..111....22222"
         // compacted: "022111222"
         // checksum = 0*0 + 1*2 + 2*2 + 3*1 + 4*1 + 5*1 + 6*2 + 7*2 + 8*2
@@ -106,7 +112,6 @@ mod test {
             .map(|c| c.to_string().parse::<usize>().unwrap())
             .collect::<Vec<usize>>()
             .chunks(2)
-            .into_iter()
             .enumerate()
             .map(parse_chunk)
             .flat_map(expand_chunk)
@@ -125,7 +130,6 @@ mod test {
             .map(|c| c.to_string().parse::<usize>().unwrap())
             .collect::<Vec<usize>>()
             .chunks(2)
-            .into_iter()
             .enumerate()
             .map(parse_chunk)
             .flat_map(expand_chunk)
@@ -1,20 +1,25 @@
+use aoc_grid::XY;
+use aoc_solution::Solution;
 use itertools::Itertools;
-use std::{
-    collections::{HashMap, HashSet},
-    fs::read_to_string,
-};
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
 
-fn main() {
-    let input = read_to_string("./input.txt").unwrap();
+pub struct Day10;
 
-    println!("part_1: {}", part_1(&input));
-    println!("part_2: {}", part_2(&input));
+impl Solution for Day10 {
+    fn part_1(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        Ok(part_1(input).to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        Ok(part_2(input).to_string())
+    }
 }
 
 fn part_1(input: &str) -> usize {
     find_trailheads(input)
         .values()
-        .map(unique_reachable_peaks_for_paths)
+        .map(|paths| unique_reachable_peaks_for_paths(paths))
         .map(|reachable_peaks| reachable_peaks.len())
         .sum()
 }
@@ -26,26 +31,6 @@ fn part_2(input: &str) -> usize {
         .sum()
 }
 
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
-struct XY(usize, usize);
-
-impl XY {
-    fn neighbors(&self) -> Vec<XY> {
-        match self {
-            XY(0, 0) => vec![XY(1, 0), XY(0, 1)],
-            XY(0, y) => vec![XY(0, y - 1), XY(0, y + 1), XY(1, *y)],
-            XY(x, 0) => vec![XY(x - 1, 0), XY(x + 1, 0), XY(*x, 1)],
-            XY(x, y) => vec![XY(x - 1, *y), XY(x + 1, *y), XY(*x, y - 1), XY(*x, y + 1)],
-        }
-    }
-}
-
-impl From<(usize, usize)> for XY {
-    fn from((x, y): (usize, usize)) -> Self {
-        XY(x, y)
-    }
-}
-
 type Path = Vec<XY>;
 
 fn group_squares_by_value(input: &str) -> HashMap<usize, Vec<XY>> {
@@ -74,7 +59,7 @@ fn find_trailheads(input: &str) -> HashMap<XY, Vec<Vec<XY>>> {
     previous
 }
 
-fn unique_reachable_peaks_for_paths(paths: &Vec<Path>) -> HashSet<XY> {
+fn unique_reachable_peaks_for_paths(paths: &[Path]) -> HashSet<XY> {
     paths
         .iter()
         .map(|path| path.first().unwrap())
@@ -82,7 +67,7 @@ fn unique_reachable_peaks_for_paths(paths: &Vec<Path>) -> HashSet<XY> {
         .collect()
 }
 
-fn find_paths(previous: &HashMap<XY, Vec<Path>>, squares: &Vec<XY>) -> HashMap<XY, Vec<Path>> {
+fn find_paths(previous: &HashMap<XY, Vec<Path>>, squares: &[XY]) -> HashMap<XY, Vec<Path>> {
     squares
         .iter()
         .map(|square| {
@@ -90,12 +75,11 @@ fn find_paths(previous: &HashMap<XY, Vec<Path>>, squares: &Vec<XY>) -> HashMap<X
                 *square,
                 square
                     .neighbors()
-                    .iter()
-                    .filter_map(|path| previous.get(path))
+                    .filter_map(|neighbor| previous.get(&neighbor))
                     .flatten()
                     .map(|path| {
                         let mut path = path.clone();
-                        path.push(square.clone());
+                        path.push(*square);
                         path
                     })
                     .collect::<Vec<Path>>(),
@@ -104,7 +88,7 @@ fn find_paths(previous: &HashMap<XY, Vec<Path>>, squares: &Vec<XY>) -> HashMap<X
         .collect()
 }
 
-fn initialize_peaks(coords: &Vec<XY>) -> HashMap<XY, Vec<Path>> {
+fn initialize_peaks(coords: &[XY]) -> HashMap<XY, Vec<Path>> {
     coords.iter().map(|xy| (*xy, vec![vec![*xy]])).collect()
 }
 
@@ -124,7 +108,7 @@ mod test {
             01329801
             10456732
         ";
-        let result = part_1(&input);
+        let result = part_1(input);
 
         assert_eq!(result, 36);
     }
@@ -141,7 +125,7 @@ mod test {
             01329801
             10456732
         ";
-        let result = part_2(&input);
+        let result = part_2(input);
 
         assert_eq!(result, 81);
     }
@@ -154,7 +138,7 @@ mod test {
             8765
             9876
         ";
-        let result = part_1(&input);
+        let result = part_1(input);
 
         assert_eq!(result, 1);
     }
@@ -170,7 +154,7 @@ mod test {
             8.....8
             9.....9
         ";
-        let result = part_1(&input);
+        let result = part_1(input);
 
         assert_eq!(result, 2);
     }
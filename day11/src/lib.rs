@@ -1,13 +1,20 @@
 mod step;
 
-use std::{fs::read_to_string, num::ParseIntError};
+use aoc_solution::Solution;
+use std::error::Error;
+use std::num::ParseIntError;
 use step::step;
 
-fn main() {
-    let input = read_to_string("input.txt").unwrap();
+pub struct Day11;
 
-    println!("part_1: {}", part_1(&input));
-    println!("part_2: {}", part_2(&input));
+impl Solution for Day11 {
+    fn part_1(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        Ok(part_1(input).to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        Ok(part_2(input).to_string())
+    }
 }
 
 fn part_1(input: &str) -> usize {
@@ -24,7 +31,6 @@ fn part_2(input: &str) -> usize {
 
 fn parse_stones(input: &str) -> Result<Vec<(usize, usize)>, ParseIntError> {
     Ok(input
-        .trim()
         .split_whitespace()
         .map(|number| number.parse::<usize>())
         .collect::<Result<Vec<usize>, _>>()?
@@ -40,7 +46,7 @@ mod test {
     #[test]
     fn test_part_1_example_1() {
         let input = "125 17";
-        let result = part_1(&input);
+        let result = part_1(input);
 
         assert_eq!(result, 55312);
     }
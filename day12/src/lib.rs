@@ -1,49 +1,57 @@
 mod region;
 
+use aoc_grid::{Grid, GridParseError};
+use aoc_solution::Solution;
 use region::Region;
-use std::fs::read_to_string;
+use std::convert::Infallible;
+use std::error::Error;
 
-fn main() {
-    let input = read_to_string("./input.txt").unwrap();
-    let regions = parse_to_regions(&input);
+pub struct Day12;
 
-    println!("part_1: {}", part_1(&regions));
-    println!("part_2: {}", part_2(&regions));
+impl Solution for Day12 {
+    fn part_1(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        Ok(part_1(&parse_to_regions(input)?).to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        Ok(part_2(&parse_to_regions(input)?).to_string())
+    }
 }
 
-fn part_1(regions: &Vec<Region>) -> usize {
+fn part_1(regions: &[Region]) -> usize {
     regions.iter().map(|r| r.circumference_based_price()).sum()
 }
 
-fn part_2(regions: &Vec<Region>) -> usize {
+fn part_2(regions: &[Region]) -> usize {
     regions.iter().map(|r| r.sides_based_price()).sum()
 }
 
-fn parse_to_regions(input: &str) -> Vec<Region> {
-    input
-        .trim()
-        .lines()
-        .enumerate()
-        .flat_map(|(y, l)| l.trim().chars().enumerate().map(move |(x, c)| (c, (x, y))))
-        .fold(vec![], |mut acc, cell| {
-            match acc.iter_mut().find(|r| r.contains(&cell)) {
-                Some(_) => acc,
-                None => {
-                    acc.push(Region::from((input, cell)));
-                    acc
-                }
+fn parse_to_regions(input: &str) -> Result<Vec<Region>, GridParseError<Infallible>> {
+    let grid: Grid<char> = input.parse()?;
+
+    let regions = grid.iter().fold(vec![], |mut acc, (xy, plant)| {
+        let cell = (*plant, xy);
+        match acc.iter().any(|r: &Region| r.contains(&cell)) {
+            true => acc,
+            false => {
+                acc.push(Region::from((&grid, cell)));
+                acc
             }
-        })
+        }
+    });
+
+    Ok(regions)
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use std::fs::read_to_string;
 
     #[test]
     fn test_part_1_example() {
         let input = read_to_string("./input_example.txt").unwrap();
-        let regions = parse_to_regions(&input);
+        let regions = parse_to_regions(&input).unwrap();
         let result = part_1(&regions);
 
         assert_eq!(result, 772);
@@ -52,7 +60,7 @@ mod test {
     #[test]
     fn test_part_1_small_example() {
         let input = read_to_string("./input_small_example.txt").unwrap();
-        let regions = parse_to_regions(&input);
+        let regions = parse_to_regions(&input).unwrap();
         let result = part_1(&regions);
 
         assert_eq!(result, 140);
@@ -61,7 +69,7 @@ mod test {
     #[test]
     fn test_part_1_larger_example() {
         let input = read_to_string("./input_larger_example.txt").unwrap();
-        let regions = parse_to_regions(&input);
+        let regions = parse_to_regions(&input).unwrap();
         let result = part_1(&regions);
 
         assert_eq!(result, 1930);
@@ -70,7 +78,7 @@ mod test {
     #[test]
     fn test_part_2_example() {
         let input = read_to_string("./input_example.txt").unwrap();
-        let regions = parse_to_regions(&input);
+        let regions = parse_to_regions(&input).unwrap();
         let result = part_2(&regions);
 
         assert_eq!(result, 436);
@@ -79,7 +87,7 @@ mod test {
     #[test]
     fn test_part_2_small_example() {
         let input = read_to_string("./input_small_example.txt").unwrap();
-        let regions = parse_to_regions(&input);
+        let regions = parse_to_regions(&input).unwrap();
         let result = part_2(&regions);
 
         assert_eq!(result, 80);
@@ -93,7 +101,7 @@ mod test {
             BBCC
             EEEC
         ";
-        let regions = parse_to_regions(&input);
+        let regions = parse_to_regions(input).unwrap();
 
         assert_eq!(regions.len(), 5);
     }
@@ -0,0 +1,175 @@
+mod machine;
+
+use aoc_solution::Solution;
+use machine::Machine;
+use std::error::Error;
+use thiserror::Error;
+
+pub use machine::{MachineParseErr, Presses, Rules};
+
+/// how far part 2's prizes actually are from where the input says
+const ADJUSTMENT: usize = 10000000000000;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    fn part_1(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        Ok(part_1(input)?.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        Ok(part_2(input)?.to_string())
+    }
+}
+
+#[derive(Debug, Error)]
+enum Day13Error {
+    #[error("Failed to parse machine")]
+    MachineParseErr(#[from] MachineParseErr),
+
+    #[error("Winning every prize takes more tokens than fit in a usize")]
+    TokensOverflow,
+}
+
+use Day13Error::*;
+
+fn part_1(input: &str) -> Result<usize, Day13Error> {
+    total_tokens(cheapest_presses(input, &Rules::PART_1, 0)?)
+}
+
+fn part_2(input: &str) -> Result<usize, Day13Error> {
+    total_tokens(cheapest_presses(input, &Rules::PART_2, ADJUSTMENT)?)
+}
+
+fn total_tokens(presses: Vec<Option<Presses>>) -> Result<usize, Day13Error> {
+    presses
+        .into_iter()
+        .flatten()
+        .try_fold(0usize, |total, presses| total.checked_add(presses.tokens))
+        .ok_or(TokensOverflow)
+}
+
+/// the cheapest way to win each machine's prize, moved `offset` further along
+/// both axes, or `None` for the machines that can't be won
+pub fn cheapest_presses(
+    input: &str,
+    rules: &Rules,
+    offset: usize,
+) -> Result<Vec<Option<Presses>>, MachineParseErr> {
+    input
+        .trim()
+        .split("\n\n")
+        .map(|machine| {
+            let mut machine: Machine = machine.parse()?;
+            machine.prize =
+                machine
+                    .prize
+                    .checked_add(offset)
+                    .ok_or(MachineParseErr::PrizeOffsetOverflow {
+                        x: machine.prize.x,
+                        y: machine.prize.y,
+                        offset,
+                    })?;
+            Ok(machine.cheapest(rules))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs::read_to_string;
+
+    #[test]
+    fn test_part_1_example() {
+        let input = read_to_string("./input_example.txt").unwrap();
+        let result = part_1(&input).unwrap();
+
+        assert_eq!(result, 480);
+    }
+
+    #[test]
+    fn test_part_2_example() {
+        let input = read_to_string("./input_example.txt").unwrap();
+        let result = part_2(&input).unwrap();
+
+        assert_eq!(result, 875318608908);
+    }
+
+    #[test]
+    fn test_cheapest_presses_example() {
+        let input = read_to_string("./input_example.txt").unwrap();
+        let result = cheapest_presses(&input, &Rules::PART_2, ADJUSTMENT).unwrap();
+
+        // only the second and fourth machines can be won once the prizes move
+        assert_eq!(result[0], None);
+        assert!(result[1].is_some());
+        assert_eq!(result[2], None);
+        assert!(result[3].is_some());
+    }
+
+    #[test]
+    fn test_cheapest_presses_offset_overflow() {
+        let input = read_to_string("./input_example.txt").unwrap();
+        let result = cheapest_presses(&input, &Rules::PART_2, usize::MAX);
+
+        assert!(matches!(
+            result,
+            Err(MachineParseErr::PrizeOffsetOverflow {
+                x: 8400,
+                y: 5400,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_total_tokens_overflow() {
+        let presses = |tokens| Some(Presses { a: 0, b: 0, tokens });
+
+        assert_eq!(
+            total_tokens(vec![presses(usize::MAX), None]).unwrap(),
+            usize::MAX
+        );
+        assert!(matches!(
+            total_tokens(vec![presses(usize::MAX), None, presses(1)]),
+            Err(TokensOverflow)
+        ));
+    }
+
+    #[test]
+    fn test_part_1_base_case() {
+        let input = "
+            Button A: X+1, Y+1
+            Button B: X+2, Y+2
+            Prize: X=1, Y=1
+        ";
+        let result = part_1(input).unwrap();
+
+        assert_eq!(result, 3);
+    }
+
+    #[test]
+    fn test_part_1_example_machine_1() {
+        let input = "
+            Button A: X+94, Y+34
+            Button B: X+22, Y+67
+            Prize: X=8400, Y=5400
+        ";
+        let result = cheapest_presses(input, &Rules::PART_1, 0).unwrap();
+
+        assert_eq!(result[0].map(|presses| presses.tokens), Some(280));
+    }
+
+    #[test]
+    fn test_part_2_example_machine_4() {
+        let input = "
+            Button A: X+69, Y+23
+            Button B: X+27, Y+71
+            Prize: X=18641, Y=10279
+        ";
+        let result = cheapest_presses(input, &Rules::PART_2, ADJUSTMENT).unwrap();
+
+        assert!(result[0].is_some());
+    }
+}
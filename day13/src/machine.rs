@@ -1,5 +1,6 @@
 mod button;
 mod prize;
+mod solver;
 
 use button::{Button, ButtonParseError};
 use itertools::Itertools;
@@ -9,6 +10,8 @@ use thiserror::Error;
 
 use crate::machine::prize::PrizeParseError;
 
+pub use solver::{Presses, Rules};
+
 #[derive(Debug, Eq, PartialEq)]
 pub struct Machine {
     pub(crate) a: Button,
@@ -26,6 +29,9 @@ pub enum MachineParseErr {
 
     #[error("Failed to parse prize")]
     PrizeParseError(#[from] PrizeParseError),
+
+    #[error("Moving the prize at {x}, {y} by {offset} overflows")]
+    PrizeOffsetOverflow { x: usize, y: usize, offset: usize },
 }
 
 impl FromStr for Machine {
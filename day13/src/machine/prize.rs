@@ -1,5 +1,5 @@
 use itertools::Itertools;
-use std::{num::ParseIntError, ops::Add, str::FromStr};
+use std::{num::ParseIntError, str::FromStr};
 use thiserror::Error;
 
 #[derive(Debug, Eq, PartialEq)]
@@ -8,23 +8,14 @@ pub(crate) struct Prize {
     pub(crate) y: usize,
 }
 
-impl Add<usize> for Prize {
-    type Output = Prize;
-
-    fn add(self, rhs: usize) -> Self::Output {
-        Prize {
-            x: self.x + rhs,
-            y: self.y + rhs,
-        }
-    }
-}
-
-impl Into<(isize, isize)> for Prize {
-    fn into(self) -> (isize, isize) {
-        (
-            isize::try_from(self.x).unwrap(),
-            isize::try_from(self.y).unwrap(),
-        )
+impl Prize {
+    /// the prize moved `offset` further along both axes, unless that
+    /// overflows
+    pub(crate) fn checked_add(&self, offset: usize) -> Option<Prize> {
+        Some(Prize {
+            x: self.x.checked_add(offset)?,
+            y: self.y.checked_add(offset)?,
+        })
     }
 }
 
@@ -0,0 +1,296 @@
+use aoc_math::extended_gcd;
+
+use super::Machine;
+
+/// what each button costs, and how many times it may be pressed
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Rules {
+    pub a_cost: usize,
+    pub b_cost: usize,
+    pub max_presses: Option<usize>,
+}
+
+impl Rules {
+    pub const PART_1: Rules = Rules {
+        a_cost: 3,
+        b_cost: 1,
+        max_presses: Some(100),
+    };
+
+    pub const PART_2: Rules = Rules {
+        max_presses: None,
+        ..Rules::PART_1
+    };
+
+    /// the most presses of a button, which have to fit in a usize even
+    /// without a limit
+    fn limit(&self) -> i128 {
+        self.max_presses.unwrap_or(usize::MAX) as i128
+    }
+}
+
+/// the cheapest way to win a prize
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Presses {
+    pub a: usize,
+    pub b: usize,
+    pub tokens: usize,
+}
+
+impl Machine {
+    /// the cheapest presses that land exactly on the prize, if there are any
+    /// within the rules. Machines too big to work out in an i128 are treated
+    /// as unwinnable, like ones that cost more tokens than fit in a usize.
+    pub(crate) fn cheapest(&self, rules: &Rules) -> Option<Presses> {
+        let (ax, ay) = (self.a.x as i128, self.a.y as i128);
+        let (bx, by) = (self.b.x as i128, self.b.y as i128);
+        let (px, py) = (self.prize.x as i128, self.prize.y as i128);
+
+        let (a, b) = match cross((ax, ay), (bx, by))? {
+            0 => collinear((ax, ay), (bx, by), (px, py), rules)?,
+            det => {
+                let a = cross((px, py), (bx, by))?;
+                let b = cross((ax, ay), (px, py))?;
+                match (a % det, b % det) {
+                    (0, 0) => (a / det, b / det),
+                    _ => return None,
+                }
+            }
+        };
+
+        presses(a, b, rules)
+    }
+}
+
+/// checks the presses are allowed and prices them
+fn presses(a: i128, b: i128, rules: &Rules) -> Option<Presses> {
+    let limit = rules.limit();
+    if !(0..=limit).contains(&a) || !(0..=limit).contains(&b) {
+        return None;
+    }
+
+    let tokens = a
+        .checked_mul(rules.a_cost as i128)?
+        .checked_add(b.checked_mul(rules.b_cost as i128)?)?;
+
+    Some(Presses {
+        a: a as usize,
+        b: b as usize,
+        tokens: usize::try_from(tokens).ok()?,
+    })
+}
+
+/// both buttons move along the same line, so there's either no way to reach
+/// the prize or a whole family of them, of which the cheapest is picked
+fn collinear(
+    a: (i128, i128),
+    b: (i128, i128),
+    prize: (i128, i128),
+    rules: &Rules,
+) -> Option<(i128, i128)> {
+    if cross(a, prize)? != 0 || cross(b, prize)? != 0 {
+        return None;
+    }
+
+    // the prize is on the buttons' line, so one axis is enough, as long as
+    // it isn't one neither button moves along
+    let (u, v, w) = match (a.0, b.0) {
+        (0, 0) => (a.1, b.1, prize.1),
+        _ => (a.0, b.0, prize.0),
+    };
+
+    match (u, v) {
+        // neither button moves at all
+        (0, 0) => (prize == (0, 0)).then_some((0, 0)),
+        (0, v) => (w % v == 0).then_some((0, w / v)),
+        (u, 0) => (w % u == 0).then_some((w / u, 0)),
+        (u, v) => cheapest_on_line(u, v, w, rules),
+    }
+}
+
+/// the cheapest non-negative a and b with a * u + b * v = w, where u and v are
+/// both positive
+fn cheapest_on_line(u: i128, v: i128, w: i128, rules: &Rules) -> Option<(i128, i128)> {
+    let (gcd, p, q) = extended_gcd(u, v);
+    if w % gcd != 0 {
+        return None;
+    }
+
+    // every solution is (a0 + k * step_a, b0 - k * step_b) for some k
+    let (a0, b0) = (p.checked_mul(w / gcd)?, q.checked_mul(w / gcd)?);
+    let (step_a, step_b) = (v / gcd, u / gcd);
+    let limit = rules.limit();
+
+    // a between 0 and the limit, then b too
+    let low = div_ceil(a0.checked_neg()?, step_a).max(div_ceil(b0.checked_sub(limit)?, step_b));
+    let high = div_floor(b0, step_b).min(div_floor(limit.checked_sub(a0)?, step_a));
+    if low > high {
+        return None;
+    }
+
+    // the cost changes by the same amount with each step of k, so the
+    // cheapest is at one end
+    let slope = (rules.a_cost as i128)
+        .checked_mul(step_a)?
+        .checked_sub((rules.b_cost as i128).checked_mul(step_b)?)?;
+    let k = match slope > 0 {
+        true => low,
+        false => high,
+    };
+
+    Some((
+        a0.checked_add(k.checked_mul(step_a)?)?,
+        b0.checked_sub(k.checked_mul(step_b)?)?,
+    ))
+}
+
+/// the cross product of two vectors, if it fits in an i128
+fn cross((x1, y1): (i128, i128), (x2, y2): (i128, i128)) -> Option<i128> {
+    x1.checked_mul(y2)?.checked_sub(y1.checked_mul(x2)?)
+}
+
+fn div_floor(a: i128, b: i128) -> i128 {
+    a.div_euclid(b)
+}
+
+fn div_ceil(a: i128, b: i128) -> i128 {
+    a.div_euclid(b) + (a.rem_euclid(b) != 0) as i128
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn cheapest(machine: &str, rules: Rules) -> Option<Presses> {
+        machine.parse::<Machine>().unwrap().cheapest(&rules)
+    }
+
+    fn machine(a: (usize, usize), b: (usize, usize), prize: (usize, usize)) -> String {
+        format!(
+            "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}",
+            a.0, a.1, b.0, b.1, prize.0, prize.1
+        )
+    }
+
+    #[test]
+    fn test_example_machine_1() {
+        let result = cheapest(&machine((94, 34), (22, 67), (8400, 5400)), Rules::PART_1);
+
+        assert_eq!(
+            result,
+            Some(Presses {
+                a: 80,
+                b: 40,
+                tokens: 280
+            })
+        );
+    }
+
+    #[test]
+    fn test_press_limit() {
+        let input = machine((1, 0), (0, 1), (101, 1));
+
+        assert_eq!(cheapest(&input, Rules::PART_1), None);
+        assert_eq!(cheapest(&input, Rules::PART_2).map(|p| p.a), Some(101));
+    }
+
+    #[test]
+    fn test_collinear_picks_the_cheapest() {
+        // 3a + 2b = 11 is either a = 1 and b = 4 or a = 3 and b = 1
+        let input = machine((3, 3), (2, 2), (11, 11));
+
+        assert_eq!(
+            cheapest(&input, Rules::PART_1),
+            Some(Presses {
+                a: 1,
+                b: 4,
+                tokens: 7
+            })
+        );
+
+        let expensive_b = Rules {
+            b_cost: 5,
+            ..Rules::PART_1
+        };
+        assert_eq!(
+            cheapest(&input, expensive_b),
+            Some(Presses {
+                a: 3,
+                b: 1,
+                tokens: 14
+            })
+        );
+    }
+
+    #[test]
+    fn test_collinear_with_limit() {
+        let input = machine((1, 1), (3, 3), (400, 400));
+
+        // b can only go as high as 100, leaving 100 for a
+        assert_eq!(
+            cheapest(&input, Rules::PART_1),
+            Some(Presses {
+                a: 100,
+                b: 100,
+                tokens: 400
+            })
+        );
+        assert_eq!(
+            cheapest(&machine((1, 1), (3, 3), (401, 401)), Rules::PART_1),
+            None
+        );
+    }
+
+    #[test]
+    fn test_collinear_unreachable() {
+        // off the line
+        assert_eq!(
+            cheapest(&machine((1, 1), (2, 2), (3, 4)), Rules::PART_2),
+            None
+        );
+        // on the line, but between the steps
+        assert_eq!(
+            cheapest(&machine((2, 4), (4, 8), (3, 6)), Rules::PART_2),
+            None
+        );
+    }
+
+    #[test]
+    fn test_collinear_along_one_axis() {
+        let result = cheapest(&machine((0, 2), (0, 3), (0, 7)), Rules::PART_2);
+        assert_eq!(result.map(|p| (p.a, p.b)), Some((2, 1)));
+
+        let result = cheapest(&machine((0, 0), (0, 3), (0, 6)), Rules::PART_2);
+        assert_eq!(result.map(|p| (p.a, p.b)), Some((0, 2)));
+
+        let result = cheapest(&machine((0, 0), (0, 0), (0, 0)), Rules::PART_2);
+        assert_eq!(result.map(|p| p.tokens), Some(0));
+        assert_eq!(
+            cheapest(&machine((0, 0), (0, 0), (5, 0)), Rules::PART_2),
+            None
+        );
+    }
+
+    #[test]
+    fn test_too_big_to_work_out() {
+        let max = usize::MAX;
+
+        assert_eq!(
+            cheapest(&machine((max, 3), (2, max), (5, 7)), Rules::PART_2),
+            None
+        );
+        assert_eq!(
+            cheapest(&machine((max, max), (1, 1), (max, max)), Rules::PART_2),
+            None
+        );
+        // a single press fits, but not what it costs
+        let expensive = Rules {
+            a_cost: max,
+            ..Rules::PART_2
+        };
+        assert_eq!(
+            cheapest(&machine((1, 0), (0, 1), (max, max)), expensive),
+            None
+        );
+    }
+}
@@ -0,0 +1,274 @@
+use std::ops::Range;
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use crate::robot::{RobotParseError, Robots};
+
+/// grey for the lines between frames on a contact sheet, black in a PBM
+const GAP: u8 = 224;
+const WHITE: u8 = 255;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ImageFormat {
+    /// black and white, any robot is black
+    Pbm,
+    /// greyscale, darker the more robots share a cell
+    Pgm,
+    /// the same greys as the PGM, compressed
+    #[cfg(feature = "png")]
+    Png,
+}
+
+impl FromStr for ImageFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pbm" => Ok(ImageFormat::Pbm),
+            "pgm" => Ok(ImageFormat::Pgm),
+            #[cfg(feature = "png")]
+            "png" => Ok(ImageFormat::Png),
+            #[cfg(not(feature = "png"))]
+            "png" => Err("png needs day14's png feature".to_string()),
+            _ => Err(format!(
+                "unknown image format {s}, expected pbm, pgm or png"
+            )),
+        }
+    }
+}
+
+/// which frames to draw and how
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ImageOptions {
+    pub format: ImageFormat,
+    pub bounds: (isize, isize),
+    /// the seconds to draw, one frame each
+    pub frames: Range<usize>,
+    /// frames per row of the contact sheet, or about as many as there are
+    /// rows if not given
+    pub columns: Option<usize>,
+}
+
+#[derive(Debug, Error)]
+pub enum ImageError {
+    #[error("Failed to parse a robot")]
+    RobotParseError(#[from] RobotParseError),
+
+    #[error("There are no frames between {} and {}", .0.start, .0.end)]
+    NoFrames(Range<usize>),
+
+    #[cfg(feature = "png")]
+    #[error("Failed to encode png: {0}")]
+    Png(#[from] png::EncodingError),
+}
+
+use ImageError::*;
+
+/// greys in rows, 255 for white
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct Sheet {
+    width: usize,
+    height: usize,
+    pixels: Vec<u8>,
+}
+
+impl Sheet {
+    /// every frame side by side, a line of `GAP` between each
+    pub(crate) fn new(robots: &Robots, frames: Range<usize>, columns: usize) -> Sheet {
+        let (frame_width, frame_height) = robots.bounds();
+        let (frame_width, frame_height) = (frame_width as usize, frame_height as usize);
+        let rows = frames.len().div_ceil(columns);
+
+        let width = columns * (frame_width + 1) - 1;
+        let height = rows * (frame_height + 1) - 1;
+        let mut pixels = vec![GAP; width * height];
+
+        for (i, t) in frames.enumerate() {
+            let left = (i % columns) * (frame_width + 1);
+            let top = (i / columns) * (frame_height + 1);
+
+            let mut counts = vec![0usize; frame_width * frame_height];
+            for robot in robots.at(t).iter() {
+                let (x, y) = robot.position;
+                counts[y as usize * frame_width + x as usize] += 1;
+            }
+
+            for (cell, count) in counts.into_iter().enumerate() {
+                let (x, y) = (cell % frame_width, cell / frame_width);
+                pixels[(top + y) * width + left + x] = grey(count);
+            }
+        }
+
+        Sheet {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    /// binary PBM, anything that isn't white is black
+    pub(crate) fn pbm(&self) -> Vec<u8> {
+        let mut image = format!("P4\n{} {}\n", self.width, self.height).into_bytes();
+
+        for row in self.pixels.chunks(self.width) {
+            for byte in row.chunks(8) {
+                let bits = byte
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, grey)| **grey != WHITE)
+                    .fold(0u8, |bits, (i, _)| bits | 0x80 >> i);
+                image.push(bits);
+            }
+        }
+
+        image
+    }
+
+    /// binary PGM
+    pub(crate) fn pgm(&self) -> Vec<u8> {
+        let mut image = format!("P5\n{} {}\n255\n", self.width, self.height).into_bytes();
+        image.extend_from_slice(&self.pixels);
+
+        image
+    }
+
+    #[cfg(feature = "png")]
+    pub(crate) fn png(&self) -> Result<Vec<u8>, png::EncodingError> {
+        let mut image = vec![];
+
+        let mut encoder = png::Encoder::new(&mut image, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Grayscale);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.pixels)?;
+        writer.finish()?;
+
+        Ok(image)
+    }
+}
+
+/// white for an empty cell, then darker for every robot on it
+fn grey(count: usize) -> u8 {
+    match count {
+        0 => WHITE,
+        count => 96 >> (count - 1).min(7),
+    }
+}
+
+/// draws the robots at each second in `frames` on one contact sheet, read left
+/// to right then top to bottom
+pub fn image(input: &str, options: ImageOptions) -> Result<Vec<u8>, ImageError> {
+    if options.frames.is_empty() {
+        return Err(NoFrames(options.frames));
+    }
+
+    let robots = crate::parse_robots(input, options.bounds)?;
+    let columns = options
+        .columns
+        .unwrap_or(options.frames.len().isqrt())
+        .clamp(1, options.frames.len());
+    let sheet = Sheet::new(&robots, options.frames, columns);
+
+    Ok(match options.format {
+        ImageFormat::Pbm => sheet.pbm(),
+        ImageFormat::Pgm => sheet.pgm(),
+        #[cfg(feature = "png")]
+        ImageFormat::Png => sheet.png()?,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INPUT: &str = "
+        p=0,0 v=1,0
+        p=2,1 v=0,1
+        p=2,1 v=0,1
+    ";
+
+    fn sheet(frames: Range<usize>, columns: usize) -> Sheet {
+        let robots = crate::parse_robots(INPUT, (3, 2)).unwrap();
+        Sheet::new(&robots, frames, columns)
+    }
+
+    #[test]
+    fn test_single_frame() {
+        let result = sheet(0..1, 1);
+
+        assert_eq!((result.width, result.height), (3, 2));
+        assert_eq!(result.pixels, vec![96, WHITE, WHITE, WHITE, WHITE, 48]);
+    }
+
+    #[test]
+    fn test_contact_sheet() {
+        let result = sheet(0..3, 2);
+
+        // two frames across and two down, the last one left blank
+        assert_eq!((result.width, result.height), (7, 5));
+        assert_eq!(
+            &result.pixels[0..7],
+            &[96, WHITE, WHITE, GAP, WHITE, 96, 48]
+        );
+        assert_eq!(
+            &result.pixels[7..14],
+            &[WHITE, WHITE, 48, GAP, WHITE, WHITE, WHITE]
+        );
+        assert_eq!(&result.pixels[14..21], &[GAP; 7]);
+        assert_eq!(
+            &result.pixels[21..28],
+            &[WHITE, WHITE, 96, GAP, GAP, GAP, GAP]
+        );
+        assert_eq!(
+            &result.pixels[28..35],
+            &[WHITE, WHITE, 48, GAP, GAP, GAP, GAP]
+        );
+    }
+
+    #[test]
+    fn test_pbm() {
+        let result = sheet(0..1, 1).pbm();
+
+        assert_eq!(result, b"P4\n3 2\n\x80\x20");
+    }
+
+    #[test]
+    fn test_pgm() {
+        let result = sheet(0..1, 1).pgm();
+
+        assert!(result.starts_with(b"P5\n3 2\n255\n"));
+        assert_eq!(result.len(), 11 + 6);
+    }
+
+    #[test]
+    fn test_image_errors() {
+        let options = ImageOptions {
+            format: ImageFormat::Pbm,
+            bounds: (3, 2),
+            frames: 4..4,
+            columns: None,
+        };
+
+        assert!(matches!(image(INPUT, options.clone()), Err(NoFrames(_))));
+        assert!(matches!(
+            image(
+                INPUT,
+                ImageOptions {
+                    bounds: (0, 2),
+                    frames: 0..1,
+                    ..options
+                }
+            ),
+            Err(RobotParseError(RobotParseError::InvalidBounds(_)))
+        ));
+    }
+
+    #[cfg(feature = "png")]
+    #[test]
+    fn test_png() {
+        let result = sheet(0..3, 2).png().unwrap();
+
+        assert!(result.starts_with(b"\x89PNG\r\n\x1a\n"));
+    }
+}
@@ -0,0 +1,153 @@
+mod image;
+mod partition;
+mod robot;
+mod tree;
+
+use aoc_solution::Solution;
+use robot::{Robot, RobotParseError, Robots};
+use std::error::Error;
+use thiserror::Error;
+use tree::find_christmas_tree;
+
+pub use image::{ImageError, ImageFormat, ImageOptions, image};
+pub use partition::{CellCounts, OnDivider, Partition, PartitionError};
+
+/// how far a frame has to stand out before it's called a christmas tree
+const MIN_CONFIDENCE: f64 = 4.0;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    fn part_1(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        Ok(part_1(input, (101, 103))?.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        Ok(part_2(input, (101, 103))?.to_string())
+    }
+}
+
+/// one robot per line, all moving around the same bounds, which have to be
+/// positive
+fn parse_robots(input: &str, bounds: (isize, isize)) -> Result<Robots, RobotParseError> {
+    if bounds.0 <= 0 || bounds.1 <= 0 {
+        return Err(RobotParseError::InvalidBounds(bounds));
+    }
+
+    Ok(input
+        .trim()
+        .lines()
+        .map(|l| l.trim().parse::<Robot>())
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .map(|r| r.with_bounds(bounds))
+        .collect())
+}
+
+#[derive(Debug, Error)]
+pub enum CellCountError {
+    #[error("Failed to parse a robot")]
+    RobotParseError(#[from] RobotParseError),
+
+    #[error("Failed to split the map: {0}")]
+    PartitionError(#[from] PartitionError),
+}
+
+/// how many robots are in each cell of `partition` after t seconds
+pub fn cell_counts(
+    input: &str,
+    bounds: (isize, isize),
+    t: usize,
+    partition: Partition,
+) -> Result<CellCounts, CellCountError> {
+    Ok(parse_robots(input, bounds)?.at(t).cell_counts(&partition)?)
+}
+
+#[derive(Debug, Error)]
+enum Part1Error {
+    #[error("Failed to parse a robot")]
+    RobotParseError(#[from] RobotParseError),
+
+    #[error("Failed to split the map: {0}")]
+    PartitionError(#[from] PartitionError),
+
+    #[error("The safety factor is too large for a usize")]
+    SafetyFactorOverflow(),
+}
+
+fn part_1(input: &str, bounds: (isize, isize)) -> Result<usize, Part1Error> {
+    parse_robots(input, bounds)?
+        .at(100)
+        .cell_counts(&Partition::QUADRANTS)?
+        .safety_factor()
+        .ok_or(Part1Error::SafetyFactorOverflow())
+}
+
+#[derive(Debug, Error)]
+enum Part2Error {
+    #[error("Failed to parse a robot")]
+    RobotParseError(#[from] RobotParseError),
+
+    #[error("Failed to find a christmas tree")]
+    NoChristmasTreeFound(),
+}
+
+use Part2Error::*;
+
+fn part_2(input: &str, bounds: (isize, isize)) -> Result<usize, Part2Error> {
+    let robots = parse_robots(input, bounds)?;
+
+    let tree = find_christmas_tree(&robots)
+        .filter(|tree| tree.confidence >= MIN_CONFIDENCE)
+        .ok_or(NoChristmasTreeFound())?;
+
+    Ok(tree.frame)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs::read_to_string;
+
+    #[test]
+    fn test_part_1_example() {
+        let input = read_to_string("./input_example.txt").unwrap();
+        let result = part_1(&input, (11, 7)).unwrap();
+        assert_eq!(result, 12);
+    }
+
+    #[test]
+    fn test_part_1_single_robot() {
+        let input = "p=2,4 v=2,-3";
+        let result = part_1(input, (11, 7)).unwrap();
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn test_zero_bounds() {
+        let input = read_to_string("./input_example.txt").unwrap();
+
+        for bounds in [(0, 7), (11, 0), (-1, 7)] {
+            assert!(matches!(
+                cell_counts(&input, bounds, 100, Partition::QUADRANTS),
+                Err(CellCountError::RobotParseError(
+                    RobotParseError::InvalidBounds(_)
+                ))
+            ));
+            assert!(matches!(
+                part_1(&input, bounds),
+                Err(Part1Error::RobotParseError(RobotParseError::InvalidBounds(
+                    _
+                )))
+            ));
+            assert!(part_2(&input, bounds).is_err());
+        }
+    }
+
+    #[test]
+    fn test_part_2_example_has_no_tree() {
+        let input = read_to_string("./input_example.txt").unwrap();
+        let result = part_2(&input, (11, 7));
+        assert!(matches!(result, Err(NoChristmasTreeFound())));
+    }
+}
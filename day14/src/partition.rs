@@ -0,0 +1,281 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use crate::robot::{Robot, Robots};
+
+/// where a robot sitting exactly on a divider is counted
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OnDivider {
+    /// left out of every cell, like the puzzle does
+    Skip,
+    /// in the cell to its left, or above it
+    Before,
+    /// in the cell to its right, or below it
+    After,
+}
+
+impl FromStr for OnDivider {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "skip" => Ok(OnDivider::Skip),
+            "before" => Ok(OnDivider::Before),
+            "after" => Ok(OnDivider::After),
+            _ => Err(format!(
+                "unknown divider handling {s}, expected skip, before or after"
+            )),
+        }
+    }
+}
+
+/// the map split evenly into `columns` by `rows` cells
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Partition {
+    pub columns: usize,
+    pub rows: usize,
+    pub on_divider: OnDivider,
+}
+
+impl Partition {
+    /// the puzzle's four quadrants, leaving out the robots in the middle
+    pub const QUADRANTS: Partition = Partition {
+        columns: 2,
+        rows: 2,
+        on_divider: OnDivider::Skip,
+    };
+}
+
+#[derive(Debug, Error)]
+pub enum PartitionError {
+    #[error("Can't split the map into {columns}x{rows} cells")]
+    NoCells { columns: usize, rows: usize },
+
+    #[error("Robot at {position:?} is outside the bounds {bounds:?}")]
+    OutOfBounds {
+        position: (isize, isize),
+        bounds: (isize, isize),
+    },
+}
+
+use PartitionError::*;
+
+/// how many robots ended up in each cell of a partition
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CellCounts {
+    columns: usize,
+    counts: Vec<usize>,
+}
+
+impl CellCounts {
+    /// the robots in the cell at `column` and `row`, counting from 0
+    pub fn get(&self, column: usize, row: usize) -> Option<usize> {
+        match column < self.columns {
+            true => self.counts.get(row * self.columns + column).copied(),
+            false => None,
+        }
+    }
+
+    /// every count, a row at a time
+    pub fn rows(&self) -> impl Iterator<Item = &[usize]> {
+        self.counts.chunks(self.columns)
+    }
+
+    /// the counts multiplied together, if that fits in a usize. Cells without
+    /// any robots are left out, so a lone robot has a safety factor of 1
+    /// rather than 0.
+    pub fn safety_factor(&self) -> Option<usize> {
+        self.counts
+            .iter()
+            .filter(|count| **count > 0)
+            .try_fold(1usize, |product, count| product.checked_mul(*count))
+    }
+}
+
+impl Display for CellCounts {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let width = self.counts.iter().max().unwrap_or(&0).to_string().len();
+
+        for row in self.rows() {
+            let row: Vec<String> = row.iter().map(|count| format!("{count:>width$}")).collect();
+            writeln!(f, "{}", row.join(" "))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Robots {
+    /// counts the robots in each cell of `partition`
+    pub(crate) fn cell_counts(&self, partition: &Partition) -> Result<CellCounts, PartitionError> {
+        let Partition { columns, rows, .. } = *partition;
+        if columns == 0 || rows == 0 {
+            return Err(NoCells { columns, rows });
+        }
+
+        let mut counts = vec![0; columns * rows];
+        for robot in self.iter() {
+            if let Some((column, row)) = robot.cell(partition)? {
+                counts[row * columns + column] += 1;
+            }
+        }
+
+        Ok(CellCounts { columns, counts })
+    }
+}
+
+impl Robot {
+    /// the cell of `partition` the robot is in, if it isn't skipped for being
+    /// on a divider
+    pub(crate) fn cell(
+        &self,
+        partition: &Partition,
+    ) -> Result<Option<(usize, usize)>, PartitionError> {
+        let (x, y) = self.position;
+        let (width, height) = self.bounds;
+        if !(0..width).contains(&x) || !(0..height).contains(&y) {
+            return Err(OutOfBounds {
+                position: self.position,
+                bounds: self.bounds,
+            });
+        }
+
+        let column = split(x, width, partition.columns, partition.on_divider);
+        let row = split(y, height, partition.rows, partition.on_divider);
+
+        Ok(column.zip(row))
+    }
+}
+
+/// which of `cells` even slices of `0..size` the tile at `p` is in. A tile
+/// is on a divider when its middle is exactly where two slices meet.
+fn split(p: isize, size: isize, cells: usize, on_divider: OnDivider) -> Option<usize> {
+    // the middle of the tile, p + 1/2, scaled so each slice is 1 wide, with
+    // everything doubled to stay in integers
+    let scaled = (2 * p as usize + 1) * cells;
+    let cell = scaled / (2 * size as usize);
+    let on_a_divider = scaled.is_multiple_of(2 * size as usize);
+
+    match (on_a_divider, on_divider) {
+        (false, _) | (true, OnDivider::After) => Some(cell),
+        (true, OnDivider::Before) => Some(cell - 1),
+        (true, OnDivider::Skip) => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn robots(positions: &[(isize, isize)], bounds: (isize, isize)) -> Robots {
+        positions
+            .iter()
+            .map(|position| Robot::new(*position, (0, 0), bounds))
+            .collect()
+    }
+
+    #[test]
+    fn test_quadrants() {
+        let cell = |position| {
+            Robot::new(position, (0, 0), (11, 7))
+                .cell(&Partition::QUADRANTS)
+                .unwrap()
+        };
+
+        assert_eq!(cell((0, 0)), Some((0, 0)));
+        assert_eq!(cell((7, 0)), Some((1, 0)));
+        assert_eq!(cell((0, 6)), Some((0, 1)));
+        assert_eq!(cell((10, 6)), Some((1, 1)));
+        assert_eq!(cell((5, 0)), None);
+        assert_eq!(cell((0, 3)), None);
+    }
+
+    #[test]
+    fn test_on_divider() {
+        let partition = |on_divider| Partition {
+            columns: 3,
+            rows: 1,
+            on_divider,
+        };
+        // no tile has its middle on a divider when the width is split in
+        // thirds, whether or not the dividers fall between tiles
+        assert_eq!(split(4, 9, 3, OnDivider::Skip), Some(1));
+        assert_eq!(split(5, 15, 3, OnDivider::Skip), Some(1));
+        assert_eq!(split(4, 15, 3, OnDivider::Skip), Some(0));
+
+        let sut = robots(&[(0, 0), (1, 0), (2, 0)], (3, 1));
+        let thirds = sut.cell_counts(&partition(OnDivider::Skip)).unwrap();
+        assert_eq!(thirds.rows().next(), Some(&[1, 1, 1][..]));
+
+        // but halving 3 tiles puts the divider through the middle of x = 1
+        let halves = |on_divider| {
+            sut.cell_counts(&Partition {
+                columns: 2,
+                ..partition(on_divider)
+            })
+            .unwrap()
+        };
+        assert_eq!(halves(OnDivider::Skip).to_string(), "1 1\n");
+        assert_eq!(halves(OnDivider::Before).to_string(), "2 1\n");
+        assert_eq!(halves(OnDivider::After).to_string(), "1 2\n");
+    }
+
+    #[test]
+    fn test_finer_grid() {
+        let sut = robots(&[(0, 0), (1, 1), (9, 0), (9, 9), (5, 5)], (10, 10));
+        let result = sut
+            .cell_counts(&Partition {
+                columns: 5,
+                rows: 2,
+                on_divider: OnDivider::Skip,
+            })
+            .unwrap();
+
+        assert_eq!(result.to_string(), "2 0 0 0 1\n0 0 1 0 1\n");
+        assert_eq!(result.get(0, 0), Some(2));
+        assert_eq!(result.get(5, 0), None);
+        assert_eq!(result.safety_factor(), Some(2));
+    }
+
+    #[test]
+    fn test_safety_factor_overflow() {
+        let positions: Vec<(isize, isize)> = (0..64).flat_map(|x| [(x, 0), (x, 0)]).collect();
+        let sut = robots(&positions, (64, 1));
+        let cells = |columns| Partition {
+            columns,
+            rows: 1,
+            on_divider: OnDivider::Skip,
+        };
+
+        assert_eq!(
+            sut.cell_counts(&cells(16)).unwrap().safety_factor(),
+            Some(1 << 48)
+        );
+        assert_eq!(sut.cell_counts(&cells(64)).unwrap().safety_factor(), None);
+    }
+
+    #[test]
+    fn test_errors() {
+        let sut = robots(&[(11, 0)], (11, 7));
+
+        assert!(matches!(
+            sut.cell_counts(&Partition::QUADRANTS),
+            Err(OutOfBounds {
+                position: (11, 0),
+                ..
+            })
+        ));
+        assert!(matches!(
+            sut.cell_counts(&Partition {
+                rows: 0,
+                ..Partition::QUADRANTS
+            }),
+            Err(NoCells {
+                columns: 2,
+                rows: 0
+            })
+        ));
+    }
+}
@@ -1,9 +1,5 @@
 use itertools::Itertools;
-use std::{
-    collections::{HashMap, HashSet},
-    fmt::Display,
-    str::FromStr,
-};
+use std::{collections::HashMap, fmt::Display, str::FromStr};
 use thiserror::Error;
 
 type XY = (isize, isize);
@@ -12,7 +8,7 @@ type XY = (isize, isize);
 pub(crate) struct Robot {
     pub(crate) position: XY,
     velocity: XY,
-    bounds: XY,
+    pub(crate) bounds: XY,
 }
 
 impl Robot {
@@ -26,26 +22,19 @@ impl Robot {
     }
 
     pub(crate) fn with_bounds(&self, bounds: (isize, isize)) -> Robot {
-        let mut robot = self.clone();
+        let mut robot = *self;
         robot.bounds = bounds;
         robot
     }
 
-    pub(crate) fn quadrant(&self) -> Option<u8> {
-        let half_x = self.bounds.0 / 2;
-        let half_y = self.bounds.1 / 2;
-
-        match self.position {
-            (x, y) if x == half_x || y == half_y => None,
-            (x, y) if x < half_x && y < half_y => Some(1),
-            (x, y) if half_x < x && y < half_y => Some(2),
-            (x, y) if x < half_x && half_y < y => Some(3),
-            (x, y) if half_x < x && half_y < y => Some(4),
-            _ => panic!(
-                "Position is out of bounds! position: {:?}, bounds: {:?}",
-                self.position, self.bounds
-            ),
-        }
+    /// where the robot is after t seconds. Only t modulo the bounds matters,
+    /// so any t works without stepping through it.
+    pub(crate) fn position_at(&self, t: usize) -> XY {
+        let (x, y) = self.position;
+        let (vx, vy) = self.velocity;
+        let (bx, by) = self.bounds;
+
+        (wrap(x, vx, t, bx), wrap(y, vy, t, by))
     }
 }
 
@@ -56,6 +45,9 @@ pub enum RobotParseError {
 
     #[error("Malformed integer in robot string")]
     ParseIntError(#[from] std::num::ParseIntError),
+
+    #[error("Robots can't move around bounds of {0:?}")]
+    InvalidBounds((isize, isize)),
 }
 
 use RobotParseError::*;
@@ -82,34 +74,31 @@ impl FromStr for Robot {
     }
 }
 
+/// p + v * t on an axis that wraps around at bound
+fn wrap(p: isize, v: isize, t: usize, bound: isize) -> isize {
+    // bound is positive, so it fits in a usize, and every term is reduced
+    // below it so the sum can't overflow an i128
+    let bound = bound as i128;
+    let t = t as i128 % bound;
+    let v = (v as i128).rem_euclid(bound);
+    let p = (p as i128).rem_euclid(bound);
+
+    // less than bound, so it fits back in an isize
+    ((p + v * t) % bound) as isize
+}
+
 impl Iterator for Robot {
     type Item = Robot;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let (mut x, mut y) = self.position;
-        let (vx, vy) = self.velocity;
-        let (bx, by) = self.bounds;
-
-        x += vx;
-        while x < 0 {
-            x += bx;
-        }
-        x %= bx;
-
-        y += vy;
-        while y < 0 {
-            y += by;
-        }
-        y %= by;
-
-        self.position = (x, y);
+        self.position = self.position_at(1);
         Some(*self)
     }
 }
 
-impl Into<(XY, XY)> for Robot {
-    fn into(self) -> (XY, XY) {
-        (self.position, self.velocity)
+impl From<Robot> for (XY, XY) {
+    fn from(val: Robot) -> Self {
+        (val.position, val.velocity)
     }
 }
 
@@ -120,10 +109,29 @@ pub(crate) struct Robots {
 }
 
 impl Robots {
-    pub(crate) fn might_be_christmas_tree(&self) -> bool {
-        let positions: HashSet<XY> = self.robots.iter().map(|r| r.position).collect();
+    /// everyone's position after t seconds, however large t is
+    pub(crate) fn at(&self, t: usize) -> Robots {
+        let robots = self
+            .robots
+            .iter()
+            .map(|robot| Robot {
+                position: robot.position_at(t),
+                ..*robot
+            })
+            .collect();
+
+        Robots {
+            robots,
+            bounds: self.bounds,
+        }
+    }
 
-        positions.len() == self.robots.len()
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &Robot> {
+        self.robots.iter()
+    }
+
+    pub(crate) fn bounds(&self) -> XY {
+        self.bounds
     }
 }
 
@@ -163,7 +171,7 @@ impl FromIterator<Robot> for Robots {
             robots: robots.clone(),
             bounds: robots
                 .first()
-                .and_then(|r| Some(r.bounds))
+                .map(|r| r.bounds)
                 .unwrap_or((isize::MAX, isize::MAX)),
         }
     }
@@ -233,77 +241,57 @@ mod test {
     }
 
     #[test]
-    fn test_quadrant_1() {
-        let robot = Robot {
-            position: (0, 0),
-            velocity: (0, 0), // irrelevant
-            bounds: (11, 7),
-        };
-
-        assert_eq!(robot.quadrant(), Some(1));
-    }
+    fn test_position_at_matches_iterating() {
+        let robot = Robot::new((2, 4), (-13, -3), (11, 7));
 
-    #[test]
-    fn test_quadrant_2() {
-        let robot = Robot {
-            position: (7, 0),
-            velocity: (0, 0), // irrelevant
-            bounds: (11, 7),
-        };
-
-        assert_eq!(robot.quadrant(), Some(2));
+        for (t, iterated) in robot.take(200).enumerate() {
+            assert_eq!(robot.position_at(t + 1), iterated.position);
+        }
+        assert_eq!(robot.position_at(0), (2, 4));
     }
 
     #[test]
-    fn test_quadrant_3() {
-        let robot = Robot {
-            position: (0, 6),
-            velocity: (0, 0), // irrelevant
-            bounds: (11, 7),
-        };
-
-        assert_eq!(robot.quadrant(), Some(3));
-    }
+    fn test_position_at_huge_t() {
+        let robot = Robot::new((2, 4), (2, -3), (101, 103));
+        let t = 1_000_000_000_000;
 
-    #[test]
-    fn test_quadrant_4() {
-        let robot = Robot {
-            position: (10, 6),
-            velocity: (0, 0), // irrelevant
-            bounds: (11, 7),
-        };
-
-        assert_eq!(robot.quadrant(), Some(4));
+        // every robot is back where it started after 101 * 103 seconds
+        assert_eq!(robot.position_at(t), robot.position_at(t % (101 * 103)));
+        assert_eq!(
+            robot.position_at(usize::MAX),
+            robot.position_at(usize::MAX % (101 * 103))
+        );
     }
 
     #[test]
-    fn test_quadrant_exactly_between_1_and_2() {
-        let robot = Robot {
-            position: (5, 0),
-            velocity: (0, 0), // irrelevant
-            bounds: (11, 7),
-        };
-
-        assert_eq!(robot.quadrant(), None);
-    }
+    fn test_position_at_huge_bounds() {
+        let bound = 5_000_000_000;
+        let robot = Robot::new((0, 0), (-1, -1), (bound, bound));
 
-    #[test]
-    fn test_quadrant_exactly_between_1_and_3() {
-        let robot = Robot {
-            position: (0, 3),
-            velocity: (0, 0), // irrelevant
-            bounds: (11, 7),
-        };
-
-        assert_eq!(robot.quadrant(), None);
+        assert_eq!(
+            robot.position_at(4_000_000_000),
+            (1_000_000_000, 1_000_000_000)
+        );
+        assert_eq!(
+            Robot::new((isize::MAX - 1, 0), (isize::MAX - 1, 0), (isize::MAX, 1))
+                .position_at(usize::MAX),
+            (isize::MAX - 2, 0)
+        );
     }
 
     #[test]
-    fn test_is_christmas_tree_when_the_unit_might_be_tree() {
-        let robots: Robots = vec![Robot::new((0, 0), (0, 0), (1, 1))]
-            .into_iter()
-            .collect();
-
-        assert!(robots.might_be_christmas_tree());
+    fn test_robots_at() {
+        let robots: Robots = vec![
+            Robot::new((0, 0), (1, 1), (11, 7)),
+            Robot::new((2, 4), (2, -3), (11, 7)),
+        ]
+        .into_iter()
+        .collect();
+
+        let result = robots.at(5);
+
+        assert_eq!(result, robots.clone().nth(4).unwrap());
+        // both axes line up again after 11 * 7 seconds
+        assert_eq!(result.at(72), robots);
     }
 }
@@ -0,0 +1,127 @@
+use crate::robot::Robots;
+use aoc_math::extended_gcd;
+
+/// the second a christmas tree shows up, and how sure we are of it
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Tree {
+    pub(crate) frame: usize,
+    /// how many standard deviations the tightest second is below the rest, on
+    /// the axis where it stands out the least. Noise stays around 2 or 3.
+    pub(crate) confidence: f64,
+}
+
+/// finds the second the robots bunch up into a picture.
+///
+/// x only depends on t modulo the width, and y on t modulo the height, so each
+/// axis is checked on its own for the second its positions are least spread
+/// out. The two seconds are then combined with the chinese remainder theorem.
+pub(crate) fn find_christmas_tree(robots: &Robots) -> Option<Tree> {
+    let (width, height) = robots.bounds();
+    let (x_offset, x_confidence) = tightest_offset(robots, width as usize, |t| {
+        robots.iter().map(|r| r.position_at(t).0).collect()
+    })?;
+    let (y_offset, y_confidence) = tightest_offset(robots, height as usize, |t| {
+        robots.iter().map(|r| r.position_at(t).1).collect()
+    })?;
+
+    Some(Tree {
+        frame: crt((x_offset, width as usize), (y_offset, height as usize))?,
+        confidence: x_confidence.min(y_confidence),
+    })
+}
+
+/// the second within `period` when an axis has the lowest variance, and how
+/// many standard deviations it is below the variance of every other second
+fn tightest_offset(
+    robots: &Robots,
+    period: usize,
+    positions_at: impl Fn(usize) -> Vec<isize>,
+) -> Option<(usize, f64)> {
+    // there's no variance without any robots
+    robots.iter().next()?;
+
+    let variances: Vec<f64> = (0..period).map(|t| variance(&positions_at(t))).collect();
+    let mean = variances.iter().sum::<f64>() / period as f64;
+    let (offset, lowest) = variances
+        .iter()
+        .copied()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))?;
+
+    let spread = (variances.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / period as f64).sqrt();
+    let confidence = match spread > 0.0 {
+        true => (mean - lowest) / spread,
+        false => 0.0,
+    };
+
+    Some((offset, confidence))
+}
+
+fn variance(values: &[isize]) -> f64 {
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<isize>() as f64 / n;
+
+    values
+        .iter()
+        .map(|v| (*v as f64 - mean).powi(2))
+        .sum::<f64>()
+        / n
+}
+
+/// the smallest t with t = a mod m and t = b mod n, if there is one
+fn crt((a, m): (usize, usize), (b, n): (usize, usize)) -> Option<usize> {
+    let (a, m, b, n) = (a as i128, m as i128, b as i128, n as i128);
+    let (gcd, p, _) = extended_gcd(m, n);
+
+    if (b - a) % gcd != 0 {
+        return None;
+    }
+
+    let lcm = m / gcd * n;
+    let t = a + m * ((b - a) / gcd * p).rem_euclid(n / gcd);
+
+    Some(t.rem_euclid(lcm) as usize)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::robot::Robot;
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt((2, 3), (3, 5)), Some(8));
+        assert_eq!(crt((0, 101), (0, 103)), Some(0));
+        assert_eq!(crt((100, 101), (102, 103)), Some(101 * 103 - 1));
+        assert_eq!(crt((1, 4), (3, 6)), Some(9));
+        assert_eq!(crt((1, 4), (2, 6)), None);
+    }
+
+    #[test]
+    fn test_finds_a_cluster() {
+        let bounds = (101, 103);
+        let frame = 6_174;
+        let period = 101 * 103;
+
+        // robots in a 5x5 square at `frame`, each moving its own way
+        let robots: Robots = (0..50)
+            .map(|i: isize| {
+                let velocity = ((i * 37) % 199 - 99, (i * 53) % 197 - 98);
+                let clustered = Robot::new((40 + i % 5, 60 + i / 10), velocity, bounds);
+                Robot::new(clustered.position_at(period - frame), velocity, bounds)
+            })
+            .collect();
+
+        let result = find_christmas_tree(&robots).unwrap();
+
+        assert_eq!(result.frame, frame);
+        assert!(result.confidence > 4.0, "{result:?}");
+    }
+
+    #[test]
+    fn test_no_robots() {
+        let robots: Robots = Vec::<Robot>::new().into_iter().collect();
+
+        assert_eq!(find_christmas_tree(&robots), None);
+    }
+}
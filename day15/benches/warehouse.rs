@@ -0,0 +1,30 @@
+//! Running part 2 on the real input by collecting every state, streaming
+//! events, and only computing the final state, e.g. `cargo bench -p day15`.
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use day15::Part2State;
+use std::fs::read_to_string;
+use std::hint::black_box;
+
+fn bench_part_2(c: &mut Criterion) {
+    let input = read_to_string("./input.txt").unwrap();
+    let state: Part2State = input.parse().unwrap();
+
+    let mut group = c.benchmark_group("day15_part_2");
+    group.sample_size(10);
+    group.bench_function("iter_last", |b| {
+        b.iter(|| {
+            let mut steps = black_box(&state).iter();
+            while steps.step().is_some() {}
+            steps.snapshot().score()
+        })
+    });
+    group.bench_function("events", |b| b.iter(|| black_box(&state).events().count()));
+    group.bench_function("final_state", |b| {
+        b.iter(|| black_box(&state).final_state().score())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_part_2);
+criterion_main!(benches);
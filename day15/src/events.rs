@@ -0,0 +1,145 @@
+use std::collections::VecDeque;
+use std::slice;
+
+use aoc_grid::XY;
+
+use crate::instruction::Instruction;
+use crate::warehouse::Warehouse;
+
+/// something that happened while following an instruction. Every instruction
+/// ends in `RobotMoved` or `Blocked`, after a `BoxPushed` for each box it
+/// pushed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Event {
+    RobotMoved {
+        from: XY,
+        to: XY,
+    },
+
+    /// the left edge of a box moved from one cell to the next
+    BoxPushed {
+        from: XY,
+        to: XY,
+    },
+
+    Blocked,
+}
+
+use Event::*;
+
+/// the events of a run, following one instruction at a time without copying
+/// the map
+pub struct Events<'a> {
+    warehouse: Warehouse,
+    instructions: slice::Iter<'a, Instruction>,
+    pending: VecDeque<Event>,
+}
+
+impl<'a> Events<'a> {
+    pub(crate) fn new(warehouse: Warehouse, instructions: &'a [Instruction]) -> Self {
+        Events {
+            warehouse,
+            instructions: instructions.iter(),
+            pending: VecDeque::new(),
+        }
+    }
+
+    fn follow(&mut self, instruction: Instruction) {
+        let robot = self.warehouse.robot();
+
+        let Some(pushed) = self.warehouse.pushed_boxes(instruction) else {
+            self.pending.push_back(Blocked);
+            return;
+        };
+
+        let before: Vec<XY> = pushed
+            .iter()
+            .map(|id| self.warehouse.boxes()[id.0].xy)
+            .collect();
+        self.warehouse.shift(&pushed, instruction);
+
+        for (id, from) in pushed.iter().zip(before) {
+            let to = self.warehouse.boxes()[id.0].xy;
+            self.pending.push_back(BoxPushed { from, to });
+        }
+        self.pending.push_back(RobotMoved {
+            from: robot,
+            to: self.warehouse.robot(),
+        });
+    }
+}
+
+impl Iterator for Events<'_> {
+    type Item = Event;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.pending.is_empty() {
+            let instruction = *self.instructions.next()?;
+            self.follow(instruction);
+        }
+
+        self.pending.pop_front()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn events(map: &str, instructions: &str) -> Vec<Event> {
+        let warehouse = Warehouse::parse(map, 2).unwrap();
+        let instructions: Vec<Instruction> = instructions
+            .chars()
+            .map(|c| c.try_into().unwrap())
+            .collect();
+
+        Events::new(warehouse, &instructions).collect()
+    }
+
+    #[test]
+    fn test_events() {
+        let result = events("#.O@", "<<<>");
+
+        assert_eq!(
+            result,
+            vec![
+                BoxPushed {
+                    from: XY(4, 0),
+                    to: XY(3, 0)
+                },
+                RobotMoved {
+                    from: XY(6, 0),
+                    to: XY(5, 0)
+                },
+                BoxPushed {
+                    from: XY(3, 0),
+                    to: XY(2, 0)
+                },
+                RobotMoved {
+                    from: XY(5, 0),
+                    to: XY(4, 0)
+                },
+                Blocked,
+                RobotMoved {
+                    from: XY(4, 0),
+                    to: XY(5, 0)
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_events_one_per_instruction() {
+        let input = std::fs::read_to_string("./input_example.txt").unwrap();
+        let (map, instructions) = input.split_once("\n\n").unwrap();
+        let instructions = instructions.replace('\n', "");
+
+        let result = events(map, &instructions);
+        let ends = result
+            .iter()
+            .filter(|event| !matches!(event, BoxPushed { .. }))
+            .count();
+
+        assert_eq!(ends, instructions.len());
+    }
+}
@@ -1,3 +1,4 @@
+use aoc_grid::Direction;
 use std::fmt::{Display, Write};
 use thiserror::Error;
 
@@ -12,6 +13,17 @@ pub enum Instruction {
 
 use Instruction::*;
 
+impl Instruction {
+    /// the instruction that moves back the way this one came
+    pub fn opposite(self) -> Instruction {
+        match self {
+            N => S,
+            S => N,
+            E => W,
+            W => E,
+        }
+    }
+}
 
 impl Display for Instruction {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -19,6 +31,17 @@ impl Display for Instruction {
     }
 }
 
+impl From<Instruction> for Direction {
+    fn from(val: Instruction) -> Self {
+        match val {
+            N => Direction::North,
+            S => Direction::South,
+            E => Direction::East,
+            W => Direction::West,
+        }
+    }
+}
+
 impl From<Instruction> for char {
     fn from(val: Instruction) -> Self  {
         (&val).into()
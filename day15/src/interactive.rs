@@ -0,0 +1,309 @@
+use std::fs;
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+
+use crossterm::event::{self, Event as TerminalEvent, KeyCode, KeyEventKind};
+use crossterm::{cursor, execute, terminal};
+use thiserror::Error;
+
+use crate::instruction::Instruction;
+use crate::part_2_state::{Part2State, Part2StateParseError};
+use crate::playback::redraw;
+use crate::replay::Replay;
+
+/// instructions per line of a saved log, like the puzzle input
+const LINE_LENGTH: usize = 1000;
+
+pub const KEYS: &str = "arrows or ^v<> move, u undo, r redo, s save, q quit";
+
+#[derive(Debug, Error)]
+pub enum InteractiveError {
+    #[error("Failed to parse state")]
+    ParseError(#[from] Part2StateParseError),
+
+    #[error("Terminal Error: {0}")]
+    Io(#[from] io::Error),
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Command {
+    Move(Instruction),
+    Undo,
+    Redo,
+    Save,
+    Quit,
+}
+
+use Command::*;
+
+impl Command {
+    fn from_char(c: char) -> Option<Command> {
+        match c {
+            'u' => Some(Undo),
+            'r' => Some(Redo),
+            's' => Some(Save),
+            'q' => Some(Quit),
+            c => Instruction::try_from(c).ok().map(Move),
+        }
+    }
+
+    fn from_key(key: KeyCode) -> Option<Command> {
+        match key {
+            KeyCode::Up => Some(Move(Instruction::N)),
+            KeyCode::Down => Some(Move(Instruction::S)),
+            KeyCode::Left => Some(Move(Instruction::W)),
+            KeyCode::Right => Some(Move(Instruction::E)),
+            KeyCode::Esc => Some(Quit),
+            KeyCode::Char(c) => Command::from_char(c),
+            _ => None,
+        }
+    }
+}
+
+/// a map being played by hand, keeping every move so it can be undone and
+/// saved as puzzle input
+pub(crate) struct Session {
+    map: String,
+    replay: Replay,
+    save: Option<PathBuf>,
+    /// what happened on the last command, for the status line
+    message: String,
+    quit: bool,
+}
+
+impl Session {
+    /// starts from the input's map, ignoring any instructions after it
+    pub(crate) fn new(input: &str, save: Option<&Path>) -> Result<Session, Part2StateParseError> {
+        let map = input.trim().split("\n\n").next().unwrap_or_default();
+        let map = map
+            .lines()
+            .map(|line| line.trim())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let state = Part2State::parse_map(&map)?;
+
+        Ok(Session {
+            map,
+            replay: state.replay(),
+            save: save.map(Path::to_path_buf),
+            message: String::new(),
+            quit: false,
+        })
+    }
+
+    pub(crate) fn handle(&mut self, command: Command) -> io::Result<()> {
+        self.message = match command {
+            Move(instruction) => match self.replay.follow(instruction) {
+                true => String::new(),
+                false => format!("{instruction} is blocked"),
+            },
+            Undo => match self.replay.backward() {
+                true => String::new(),
+                false => "nothing to undo".to_string(),
+            },
+            Redo => match self.replay.forward() {
+                true => String::new(),
+                false => "nothing to redo".to_string(),
+            },
+            Save => self.save()?,
+            Quit => {
+                self.quit = true;
+                String::new()
+            }
+        };
+
+        Ok(())
+    }
+
+    fn save(&self) -> io::Result<String> {
+        match &self.save {
+            None => Ok("no --save file to save to".to_string()),
+            Some(path) => {
+                fs::write(path, self.log())?;
+                Ok(format!("saved to {}", path.display()))
+            }
+        }
+    }
+
+    /// the map and every move that hasn't been undone, as puzzle input
+    pub(crate) fn log(&self) -> String {
+        let moves: String = self
+            .replay
+            .followed()
+            .iter()
+            .map(|i| char::from(*i))
+            .collect();
+        let lines: Vec<&str> = moves
+            .as_bytes()
+            .chunks(LINE_LENGTH)
+            .map(|chunk| std::str::from_utf8(chunk).unwrap_or_default())
+            .collect();
+
+        format!("{}\n\n{}\n", self.map, lines.join("\n"))
+    }
+
+    /// a status line, then the map
+    pub(crate) fn screen(&self) -> Vec<String> {
+        let status = format!(
+            "moves {} score {} {}",
+            self.replay.position(),
+            self.replay.warehouse().score(),
+            self.message,
+        )
+        .trim_end()
+        .to_string();
+
+        std::iter::once(status)
+            .chain(
+                self.replay
+                    .warehouse()
+                    .to_string()
+                    .lines()
+                    .map(String::from),
+            )
+            .collect()
+    }
+}
+
+/// plays part 2's map by hand. Reads keys from the terminal, or instruction
+/// characters from stdin when it's piped, and saves the moves to `save` on
+/// quitting or `s`.
+pub fn interact(input: &str, save: Option<&Path>) -> Result<(), InteractiveError> {
+    let mut session = Session::new(input, save)?;
+
+    match io::stdin().is_terminal() {
+        true => {
+            let mut out = io::stdout();
+            terminal::enable_raw_mode()?;
+            execute!(out, terminal::EnterAlternateScreen, cursor::Hide)?;
+
+            let result = run_terminal(&mut session, &mut out);
+
+            execute!(out, cursor::Show, terminal::LeaveAlternateScreen)?;
+            terminal::disable_raw_mode()?;
+            result?;
+        }
+        false => run_piped(&mut session, io::stdin().lock(), io::stdout())?,
+    }
+
+    if session.save.is_some() {
+        session.save()?;
+    }
+
+    Ok(())
+}
+
+fn run_terminal(session: &mut Session, out: &mut impl Write) -> io::Result<()> {
+    let mut shown = vec![];
+
+    while !session.quit {
+        let screen = session.screen();
+        if shown.is_empty() {
+            write!(out, "\x1b[{};1H{KEYS}", screen.len() + 2)?;
+        }
+        write!(out, "{}", redraw(&shown, &screen))?;
+        out.flush()?;
+        shown = screen;
+
+        if let TerminalEvent::Key(key) = event::read()?
+            && key.kind == KeyEventKind::Press
+            && let Some(command) = Command::from_key(key.code)
+        {
+            session.handle(command)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// follows every command character read, one at a time, then prints the map
+pub(crate) fn run_piped(
+    session: &mut Session,
+    commands: impl BufRead,
+    mut out: impl Write,
+) -> io::Result<()> {
+    for byte in commands.bytes() {
+        if let Some(command) = Command::from_char(char::from(byte?)) {
+            session.handle(command)?;
+        }
+        if session.quit {
+            break;
+        }
+    }
+
+    writeln!(out, "{}", session.screen().join("\n"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const MAP: &str = "
+        ######
+        #..O@#
+        #....#
+        ######
+    ";
+
+    #[test]
+    fn test_commands() {
+        let mut sut = Session::new(MAP, None).unwrap();
+
+        sut.handle(Move(Instruction::W)).unwrap();
+        sut.handle(Move(Instruction::N)).unwrap();
+        assert_eq!(sut.screen()[0], "moves 2 score 105 ^ is blocked");
+
+        for _ in 0..3 {
+            sut.handle(Undo).unwrap();
+        }
+        assert_eq!(sut.screen()[0], "moves 0 score 106 nothing to undo");
+
+        sut.handle(Redo).unwrap();
+        assert_eq!(sut.screen()[2], "##...[]@..##");
+    }
+
+    #[test]
+    fn test_piped() {
+        let mut sut = Session::new(MAP, None).unwrap();
+        let mut out = vec![];
+
+        run_piped(&mut sut, "<<\n<x^q<".as_bytes(), &mut out).unwrap();
+
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(sut.replay.position(), 4);
+        assert!(out.starts_with("moves 4 score 103\n############\n##.[]@....##\n"));
+    }
+
+    #[test]
+    fn test_log_replays_to_the_same_map() {
+        let mut sut = Session::new(MAP, None).unwrap();
+        for instruction in "<<<v>>^".chars() {
+            sut.handle(Command::from_char(instruction).unwrap())
+                .unwrap();
+        }
+
+        let log = sut.log();
+        let state: Part2State = log.parse().unwrap();
+
+        assert!(log.ends_with("\n\n<<<v>>^\n"));
+        assert_eq!(
+            state.final_state().to_string(),
+            format!("{}\n\n", sut.replay.warehouse())
+        );
+    }
+
+    #[test]
+    fn test_log_wraps_like_the_puzzle_input() {
+        let mut sut = Session::new(MAP, None).unwrap();
+        for _ in 0..LINE_LENGTH + 1 {
+            sut.handle(Move(Instruction::E)).unwrap();
+        }
+
+        let log = sut.log();
+        let lines: Vec<_> = log.lines().collect();
+
+        assert_eq!(lines.len(), 4 + 1 + 2);
+        assert_eq!(lines[5].len(), LINE_LENGTH);
+        assert_eq!(lines[6], ">");
+    }
+}
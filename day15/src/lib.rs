@@ -0,0 +1,97 @@
+mod events;
+mod instruction;
+mod interactive;
+mod part_1_state;
+mod part_2_state;
+mod playback;
+mod replay;
+mod steps;
+mod warehouse;
+
+use thiserror::Error;
+use aoc_solution::Solution;
+use std::error::Error;
+
+pub use events::{Event, Events};
+pub use interactive::{InteractiveError, KEYS, interact};
+pub use part_1_state::{Part1State, Part1StateParseError};
+pub use part_2_state::{Part2State, Part2StateParseError};
+pub use playback::{CONTROLS, PlaybackError, PlaybackOptions, asciicast, play};
+pub use replay::{Bisection, bisect};
+pub use steps::{Snapshot, Steps};
+pub use warehouse::WarehouseParseError;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    fn part_1(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        Ok(part_1(input)?.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        Ok(part_2(input)?.to_string())
+    }
+}
+
+#[derive(Debug, Error)]
+enum Part1Error {
+    #[error("Failed to parse state")]
+    ParseError(#[from] Part1StateParseError),
+}
+
+fn part_1(input: &str) -> Result<usize, Part1Error> {
+    let state: Part1State = input.parse()?;
+    Ok(state.final_state().score())
+}
+
+#[derive(Debug, Error)]
+enum Part2Error {
+    #[error("Failed to parse state")]
+    ParseError(#[from] Part2StateParseError),
+}
+
+fn part_2(input: &str) -> Result<usize, Part2Error> {
+    let state: Part2State = input.parse()?;
+    Ok(state.final_state().score())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs::read_to_string;
+
+    #[test]
+    fn test_part_1_example() {
+        let input = read_to_string("./input_example.txt").unwrap();
+        let result = part_1(&input).unwrap();
+
+        assert_eq!(result, 10092);
+    }
+
+    #[test]
+    fn test_part_1_example_small() {
+        let input = read_to_string("./input_example_small.txt").unwrap();
+        let result = part_1(&input).unwrap();
+
+        assert_eq!(result, 2028);
+    }
+
+    #[test]
+    fn test_part_2_example() {
+        let input = read_to_string("./input_example.txt").unwrap();
+        let result = part_2(&input).unwrap();
+
+        assert_eq!(result, 9021);
+    }
+
+    #[test]
+    fn test_final_state_matches_iter() {
+        let input = read_to_string("./input_example.txt").unwrap();
+        let state: Part2State = input.parse().unwrap();
+
+        let mut steps = state.iter();
+        while steps.step().is_some() {}
+
+        assert_eq!(state.final_state().to_string(), steps.snapshot().to_string());
+    }
+}
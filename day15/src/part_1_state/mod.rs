@@ -1,32 +1,40 @@
-mod iter;
-
-use std::{collections::HashMap, fmt::Display, str::FromStr};
+use crate::events::Events;
+use crate::instruction::{Instruction, InvalidInstructionError};
+use crate::steps::Steps;
+use crate::warehouse::{Warehouse, WarehouseParseError};
+use std::{fmt::Display, str::FromStr};
 use thiserror::Error;
-use iter::Iter;
-
-type XY = (usize, usize);
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Part1State {
-    tiles: HashMap<XY, char>,
-    robot: XY,
-   instructions: Vec<char>,
+    warehouse: Warehouse,
+    instructions: Vec<Instruction>,
 }
 
 impl Part1State {
-    pub(crate) fn score(&self) -> usize {
-        self.tiles
-            .iter()
-            .filter_map(|(xy, tile)| match *tile == 'O' {
-                true => Some(*xy),
-                false => None,
-            })
-            .map(|(x, y)| x + (100 * y))
-            .sum()
+    pub fn score(&self) -> usize {
+        self.warehouse.score()
+    }
+
+    /// every state along the way, one instruction at a time on a single copy
+    /// of the map
+    pub fn iter(&self) -> Steps<'_> {
+        Steps::new(self.warehouse.clone(), &self.instructions)
     }
 
-    pub(crate) fn iter(&self) -> Iter {
-        Iter::new(self)
+    /// what happens at each step, without copying the map
+    pub fn events(&self) -> Events<'_> {
+        Events::new(self.warehouse.clone(), &self.instructions)
+    }
+
+    /// the state once every instruction has been followed
+    pub fn final_state(&self) -> Part1State {
+        let mut warehouse = self.warehouse.clone();
+        for instruction in self.instructions.iter() {
+            warehouse.step(*instruction);
+        }
+
+        Part1State { warehouse, instructions: vec![] }
     }
 }
 
@@ -35,32 +43,16 @@ pub enum Part1StateParseError {
     #[error("Malformed State: {0}")]
     MalformedState(String),
 
-    #[error("No Robot found on map: {0}")]
-    NoRobotFound(String),
+    #[error("Invalid map: {0}")]
+    InvalidMap(#[from] WarehouseParseError),
+
+    #[error("Invalid direction: {0}")]
+    InvalidDirection(#[from] InvalidInstructionError),
 }
 
 impl Display for Part1State {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let max_x = self.tiles.keys().map(|(x,_y)| x).max();
-        let max_y = self.tiles.keys().map(|(_x,y)| y).max();
-
-        match (max_x, max_y) {
-            (Some(max_x), Some(max_y)) => {
-                for y in 0..=*max_y {
-                    for x in 0..=*max_x {
-                        match self.tiles.get(&(x,y)) {
-                            None => panic!("No tile available for coordinates: {:?}", (x,y)),
-                            Some(tile) => f.write_fmt(format_args!("{}",tile))?,
-                        }
-                    }
-
-                    f.write_fmt(format_args!("\n"))?;
-                }
-            }
-            _ => panic!("Received state with no tiles"),
-        };
-
-        f.write_fmt(format_args!("\n"))?;
+        f.write_fmt(format_args!("{}\n", self.warehouse))?;
 
         for instruction in self.instructions.iter() {
             f.write_fmt(format_args!("{}", *instruction))?;
@@ -77,25 +69,21 @@ impl FromStr for Part1State {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let (tiles, instructions) = s.trim().split_once("\n\n").ok_or(MalformedState(s.to_string()))?;
-        let tiles: HashMap<XY, char> = tiles.lines().enumerate().flat_map(|(y, line)| {
-            line.trim().chars().enumerate().map(move |(x, c)| ((x,y), c))
-        }).collect();
-        let instructions: Vec<char> = instructions.lines().flat_map(|line| line.trim().chars()).collect();
-
-        let robot = tiles.iter().find_map(|(xy, c)| match c {
-            '@' => Some(*xy),
-            _ => None,
-        }).ok_or(NoRobotFound(s.to_string()))?;
-
-
-
-        Ok(Part1State{ tiles, robot, instructions })
+        let warehouse = Warehouse::parse(tiles, 1)?;
+        let instructions: Vec<Instruction> = instructions
+            .lines()
+            .flat_map(|line| line.trim().chars())
+            .map(Instruction::try_from)
+            .collect::<Result<_, _>>()?;
+
+        Ok(Part1State{ warehouse, instructions })
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use aoc_grid::XY;
 
     #[test]
     fn test_score_example() {
@@ -138,13 +126,13 @@ mod test {
             <<
         ";
         let sut: Part1State = input.parse().unwrap();
-        let result = sut.iter().next();
+        let result = sut.iter().step().map(|s| s.to_string());
 
         assert_eq!(result, Some("
             @.
 
             <
-        ".parse().unwrap()));
+        ".parse::<Part1State>().unwrap().to_string()));
     }
 
     #[test]
@@ -155,13 +143,13 @@ mod test {
             <<
         ";
         let sut: Part1State = input.parse().unwrap();
-        let result = sut.iter().next();
+        let result = sut.iter().step().map(|s| s.to_string());
 
         assert_eq!(result, Some("
             #@
 
             <
-        ".parse().unwrap()));
+        ".parse::<Part1State>().unwrap().to_string()));
     }
 
     #[test]
@@ -172,13 +160,13 @@ mod test {
             <<
         ";
         let sut: Part1State = input.parse().unwrap();
-        let result = sut.iter().next();
+        let result = sut.iter().step().map(|s| s.to_string());
 
         assert_eq!(result, Some("
             O@.
 
             <
-        ".parse().unwrap()));
+        ".parse::<Part1State>().unwrap().to_string()));
     }
 
     #[test]
@@ -189,13 +177,13 @@ mod test {
             <<
         ";
         let sut: Part1State = input.parse().unwrap();
-        let result = sut.iter().next();
+        let result = sut.iter().step().map(|s| s.to_string());
 
         assert_eq!(result, Some("
             #O@
 
             <
-        ".parse().unwrap()));
+        ".parse::<Part1State>().unwrap().to_string()));
     }
 
     #[test]
@@ -207,11 +195,9 @@ mod test {
         ";
         let result: Part1State = input.parse().unwrap();
 
-        assert_eq!(result, Part1State{
-            tiles: HashMap::from([ ((0,0), '@'), ]),
-            robot: (0,0),
-            instructions: vec!['<'],
-        });
+        assert_eq!(result.warehouse.to_string(), "@\n");
+        assert_eq!(result.warehouse.robot(), XY(0,0));
+        assert_eq!(result.instructions, vec![Instruction::W]);
     }
 
     #[test]
@@ -224,11 +210,9 @@ mod test {
         ";
         let result: Part1State = input.parse().unwrap();
 
-        assert_eq!(result, Part1State{
-            tiles: HashMap::from([ ((0,0), '#'), ((1,0), '@'), ((0,1), '.'), ((1,1), 'O')]),
-            robot: (1,0),
-            instructions: vec!['<'],
-        });
+        assert_eq!(result.warehouse.to_string(), "#@\n.O\n");
+        assert_eq!(result.warehouse.robot(), XY(1,0));
+        assert_eq!(result.instructions, vec![Instruction::W]);
     }
 
     #[test]
@@ -241,10 +225,18 @@ mod test {
         ";
         let result: Part1State = input.parse().unwrap();
 
-        assert_eq!(result, Part1State{
-            tiles: HashMap::from([ ((0,0), '@') ]),
-            robot: (0,0),
-            instructions: vec!['<', '>', '^', 'v'],
-        });
+        assert_eq!(result.instructions, vec![Instruction::W, Instruction::E, Instruction::N, Instruction::S]);
+    }
+
+    #[test]
+    fn test_parse_invalid_direction() {
+        let input = "
+            @
+
+            <x
+        ";
+        let result = input.parse::<Part1State>();
+
+        assert!(matches!(result, Err(InvalidDirection(_))));
     }
 }
@@ -1,91 +1,86 @@
-mod instruction;
-mod iter;
-mod tile;
-
-use instruction::{Instruction,InvalidInstructionError};
-use iter::Iter;
-use std::{collections::HashMap, fmt::Display, str::FromStr};
+mod validate;
+
+use crate::events::Events;
+use crate::instruction::{Instruction, InvalidInstructionError};
+use crate::replay::Replay;
+use crate::steps::Steps;
+use crate::warehouse::{Warehouse, WarehouseParseError};
+use std::{fmt::Display, str::FromStr};
 use thiserror::Error;
-use tile::Tile;
-
-type XY = (usize, usize);
+use validate::{Format, validate};
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Part2State {
-    tiles: HashMap<XY, Tile>,
-    robot: XY,
+    warehouse: Warehouse,
     instructions: Vec<Instruction>,
 }
 
 impl Part2State {
     #[allow(unused)]
-    // doesn't validate that the input tiles are valid, doesn't validate that a robot exists.
-    // robot defaults to 0,0 if no present in the string. It's also totally fine if instructions
-    // are missing, it'll just default them to an empty vector
-    fn from_raw(input: &str) -> Part2State {
+    // parses a map that's already been widened, with [] for boxes. It doesn't
+    // have to be surrounded by walls, so it can be just a few rows of a map.
+    // It's totally fine if instructions are missing, it'll just default them
+    // to an empty vector
+    fn from_raw(input: &str) -> Result<Part2State, Part2StateParseError> {
         let input = input.trim();
         let (tiles_str, instructions_str) = input.split_once("\n\n").unwrap_or((input, ""));
 
-        
-        let tiles: HashMap<XY, Tile> = tiles_str.lines().enumerate().flat_map(|(y, line)| {
-            line.trim().chars().enumerate().map(move |(x, c)| ((x, y), c.try_into().unwrap()))
-        }).collect();
-
-        let (robot,_) = tiles
-            .clone()
-            .into_iter()
-            .find(|(xy, c)| *c == Tile::Robot)
-            .unwrap_or(((0,0), Tile::Robot));
+        validate(tiles_str, Format::Widened)?;
+        let warehouse = Warehouse::parse_stretched(tiles_str)?;
 
         let instructions: Vec<Instruction> = instructions_str
             .lines()
             .flat_map(|l| l.trim().chars())
-            .map(|c| c.try_into().unwrap())
-            .collect();
+            .map(Instruction::try_from)
+            .collect::<Result<_, _>>()?;
 
-        Part2State {
-            tiles, robot, instructions
-        }
+        Ok(Part2State {
+            warehouse, instructions
+        })
+    }
+
+    /// parses just the map of a puzzle input, with no instructions to follow
+    pub fn parse_map(map: &str) -> Result<Part2State, Part2StateParseError> {
+        validate(map, Format::Puzzle)?;
+        let warehouse = Warehouse::parse(map, 2)?;
+
+        Ok(Part2State{ warehouse, instructions: vec![] })
+    }
+
+    pub fn score(&self) -> usize {
+        self.warehouse.score()
     }
 
-    pub(crate) fn score(&self) -> usize {
-        self.tiles
-            .iter()
-            .filter_map(|(xy, tile)| match *tile == Tile::LeftBox {
-                true => Some(*xy),
-                false => None,
-            })
-            .map(|(x, y)| x + (100 * y))
-            .sum()
+    /// every state along the way, one instruction at a time on a single copy
+    /// of the map
+    pub fn iter(&self) -> Steps<'_> {
+        Steps::new(self.warehouse.clone(), &self.instructions)
     }
 
-    pub(crate) fn iter(&self) -> Iter {
-        Iter::new(self)
+    /// what happens at each step, without copying the map
+    pub fn events(&self) -> Events<'_> {
+        Events::new(self.warehouse.clone(), &self.instructions)
+    }
+
+    /// the state once every instruction has been followed
+    pub fn final_state(&self) -> Part2State {
+        let mut warehouse = self.warehouse.clone();
+        for instruction in self.instructions.iter() {
+            warehouse.step(*instruction);
+        }
+
+        Part2State { warehouse, instructions: vec![] }
+    }
+
+    /// a run of the instructions that can also step backwards
+    pub(crate) fn replay(&self) -> Replay {
+        Replay::new(self.warehouse.clone(), self.instructions.clone())
     }
 }
 
 impl Display for Part2State {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let max_x = self.tiles.keys().map(|(x,_y)| x).max();
-        let max_y = self.tiles.keys().map(|(_x,y)| y).max();
-
-        match (max_x, max_y) {
-            (Some(max_x), Some(max_y)) => {
-                for y in 0..=*max_y {
-                    for x in 0..=*max_x {
-                        match self.tiles.get(&(x,y)) {
-                            None => panic!("No tile available for coordinates: {:?}", (x,y)),
-                            Some(tile) => f.write_fmt(format_args!("{}", tile))?,
-                        }
-                    }
-
-                    f.write_fmt(format_args!("\n"))?;
-                }
-            }
-            _ => panic!("Received state with no tiles"),
-        };
-
-        f.write_fmt(format_args!("\n"))?;
+        f.write_fmt(format_args!("{}\n", self.warehouse))?;
 
         for instruction in self.instructions.iter() {
             f.write_fmt(format_args!("{}", *instruction))?;
@@ -100,11 +95,29 @@ pub enum Part2StateParseError {
     #[error("Malformed State: {0}")]
     MalformedState(String),
 
-    #[error("No Robot found on map: {0}")]
-    NoRobotFound(String),
+    #[error("Invalid tile '{tile}' at line {line} column {column}")]
+    InvalidTile { tile: char, line: usize, column: usize },
+
+    #[error("Box at line {line} column {column} has a '[' without a ']' after it")]
+    UnclosedBox { line: usize, column: usize },
+
+    #[error("Box at line {line} column {column} has a ']' without a '[' before it")]
+    UnopenedBox { line: usize, column: usize },
+
+    #[error("No Robot found on map")]
+    NoRobotFound,
 
-    #[error("Invalid tile: {0}")]
-    InvalidTile(char),
+    #[error("Second robot at line {line} column {column}, the first is at line {} column {}", first.0, first.1)]
+    MultipleRobots { line: usize, column: usize, first: (usize, usize) },
+
+    #[error("Line {line} has {found} tiles but the first line has {expected}")]
+    NotRectangular { line: usize, expected: usize, found: usize },
+
+    #[error("The map's edge has '{tile}' at line {line} column {column} instead of a wall")]
+    NotWallBordered { tile: char, line: usize, column: usize },
+
+    #[error("Invalid map: {0}")]
+    InvalidMap(#[from] WarehouseParseError),
 
     #[error("Invalid direction: {0}")]
     InvalidDirection(#[from] InvalidInstructionError),
@@ -117,50 +130,22 @@ impl FromStr for Part2State {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let (tiles, instructions) = s.trim().split_once("\n\n").ok_or(MalformedState(s.to_string()))?;
-        let tiles: HashMap<XY, Tile> = tiles
-            .lines()
-            .enumerate()
-            .flat_map(|(y, line)| {
-                line.trim().chars().enumerate().map(move |(x, c)| {
-                    ((x, y), c)
-                })
-            }).map(|((x, y), c)| {
-                let (x1,y1) = (x * 2, y);
-                let (x2,y2) = (x1 + 1, y1);
-
-                match c { 
-                    '@' => Ok(vec![((x1, y1), Tile::Robot), ((x2, y2), Tile::Empty)]),
-                    '#' => Ok(vec![((x1, y1), Tile::Wall), ((x2, y2), Tile::Wall)]),
-                    'O' => Ok(vec![((x1, y1), Tile::LeftBox), ((x2, y2), Tile::RightBox)]),
-                    '.' => Ok(vec![((x1, y1), Tile::Empty), ((x2, y2), Tile::Empty)]),
-                    _ => Err(Part2StateParseError::InvalidTile(c)),
-                }
-            }).collect::<Result<Vec<_>, Part2StateParseError>>()?
-              .into_iter()
-              .flatten()
-              .collect();
+        let warehouse = Part2State::parse_map(tiles)?.warehouse;
 
         let instructions: Vec<Instruction> = instructions
             .lines()
             .flat_map(|line| line.trim().chars())
-            .map(|c| Instruction::try_from(c))
+            .map(Instruction::try_from)
             .collect::<Result<_, _>>()?;
 
-        let robot = tiles.iter().find_map(|(xy, c)| match c {
-            Tile::Robot => Some(*xy),
-            _ => None,
-        }).ok_or(NoRobotFound(s.to_string()))?;
-
-
-
-        Ok(Part2State{ tiles, robot, instructions })
+        Ok(Part2State{ warehouse, instructions })
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use Tile::*;
+    use aoc_grid::XY;
 
     #[test]
     fn test_score_example() {
@@ -169,7 +154,7 @@ mod test {
             ##...[]...
             ##.....@..
         ";
-        let sut: Part2State = Part2State::from_raw(input);
+        let sut: Part2State = Part2State::from_raw(input).unwrap();
         let result = sut.score();
         assert_eq!(result, 105);
     }
@@ -188,7 +173,7 @@ mod test {
             ##......[][]..[]..##
             ####################
         ";
-        let sut: Part2State = Part2State::from_raw(input);
+        let sut: Part2State = Part2State::from_raw(input).unwrap();
         let result = sut.score();
         assert_eq!(result, 9021);
     }
@@ -199,31 +184,35 @@ mod test {
             ..@.
 
             <<
-        ");
-        let result = sut.iter().next().unwrap();
+        ").unwrap();
+        let result = sut.iter().step().unwrap().to_string();
 
         assert_eq!(result, Part2State::from_raw("
             .@..
 
             <
-        "), "\n\nresult:\n{}", result);
+        ").unwrap().to_string(), "\n\nresult:\n{}", result);
     }
 
     #[test]
     fn test_iter_wall() {
         let input = "
-            #@
+            ####
+            #@.#
+            ####
 
             <<
         ";
         let sut: Part2State = input.parse().unwrap();
-        let result = sut.iter().next().unwrap();
+        let result = sut.iter().step().unwrap().to_string();
 
         assert_eq!(result, Part2State::from_raw("
-            ##@.
+            ########
+            ##@...##
+            ########
 
             <
-        "));
+        ").unwrap().to_string());
     }
 
     #[test]
@@ -232,14 +221,14 @@ mod test {
             ..[]@.
 
             <<
-        ");
-        let result = sut.iter().next().unwrap();
+        ").unwrap();
+        let result = sut.iter().step().unwrap().to_string();
 
         assert_eq!(result, Part2State::from_raw("
             .[]@..
 
             <
-        "), "\n\nstate:\n{}", result);
+        ").unwrap().to_string(), "\n\nstate:\n{}", result);
     }
 
     #[test]
@@ -250,8 +239,8 @@ mod test {
             @.
 
             ^v
-        ");
-        let result = sut.iter().next().unwrap();
+        ").unwrap();
+        let result = sut.iter().step().unwrap().to_string();
 
         assert_eq!(result, Part2State::from_raw("
             []
@@ -259,7 +248,7 @@ mod test {
             ..
 
             v
-        "), "\n\nstate:\n{}", result);
+        ").unwrap().to_string(), "\n\nstate:\n{}", result);
     }
 
     #[test]
@@ -268,64 +257,93 @@ mod test {
             ##[]@.
 
             <<
-        ");
-        let result = sut.iter().next();
+        ").unwrap();
+        let result = sut.iter().step().map(|s| s.to_string());
 
         assert_eq!(result, Some(Part2State::from_raw("
             ##[]@.
 
             <
-        ")), "\n\nstate:\n{}", sut);
+        ").unwrap().to_string()), "\n\nstate:\n{}", sut);
     }
 
     #[test]
     fn test_parse_base() {
         let input = "
-            @
+            ###
+            #@#
+            ###
 
             <
         ";
         let result: Part2State = input.parse().unwrap();
 
-        assert_eq!(result, Part2State{
-            tiles: HashMap::from([ ((0,0), Robot), ((1, 0), Empty)]),
-            robot: (0,0),
-            instructions: vec![Instruction::W],
-        });
+        assert_eq!(result.warehouse.to_string(), "######\n##@.##\n######\n");
+        assert_eq!(result.warehouse.robot(), XY(2,1));
+        assert_eq!(result.instructions, vec![Instruction::W]);
     }
 
     #[test]
     fn test_parse_multiline_map() {
         let input = "
-            #@
-            .O
+            ####
+            #@.#
+            #.O#
+            ####
 
             <
         ";
         let result: Part2State = input.parse().unwrap();
 
-        assert_eq!(result, Part2State{
-            tiles: HashMap::from([ ((0,0), Wall), ((1,0), Wall), ((2,0), Robot), ((3,0), Empty),
-                                   ((0,1), Empty), ((1,1), Empty), ((2,1), LeftBox), ((3,1), RightBox)]),
-            robot: (2,0),
-            instructions: vec![Instruction::W],
-        });
+        assert_eq!(result.warehouse.to_string(), "########\n##@...##\n##..[]##\n########\n");
+        assert_eq!(result.warehouse.robot(), XY(2,1));
+        assert_eq!(result.instructions, vec![Instruction::W]);
     }
 
     #[test]
     fn test_parse_multiline_instructions() {
         let input = "
-            @
+            ###
+            #@#
+            ###
 
             <>
             ^v
         ";
         let result: Part2State = input.parse().unwrap();
 
-        assert_eq!(result, Part2State{
-            tiles: HashMap::from([ ((0,0), Robot), ((1,0), Empty) ]),
-            robot: (0,0),
-            instructions: vec![Instruction::W, Instruction::E, Instruction::N, Instruction::S],
-        });
+        assert_eq!(result, Part2State::from_raw("
+            ######
+            ##@.##
+            ######
+
+            <>^v
+        ").unwrap());
+    }
+
+    #[test]
+    fn test_parse_invalid_map() {
+        let input = "
+            ####
+            #@.#
+            #.@#
+            ####
+
+            <
+        ";
+        let result = input.parse::<Part2State>();
+
+        assert!(matches!(result, Err(MultipleRobots { line: 3, column: 3, first: (2, 2) })));
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Second robot at line 3 column 3, the first is at line 2 column 2"
+        );
+    }
+
+    #[test]
+    fn test_from_raw_invalid() {
+        assert!(matches!(Part2State::from_raw("..[.@"), Err(UnclosedBox { line: 1, column: 3 })));
+        assert!(matches!(Part2State::from_raw("..[]"), Err(NoRobotFound)));
+        assert!(matches!(Part2State::from_raw("@.\n\n<x"), Err(InvalidDirection(_))));
     }
 }
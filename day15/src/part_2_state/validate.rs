@@ -0,0 +1,171 @@
+use super::Part2StateParseError::{self, *};
+
+/// how a map is written
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Format {
+    /// the puzzle input, with `O` for boxes, which has to be surrounded by
+    /// walls
+    Puzzle,
+    /// already widened, with `[]` for boxes. Usually just a few rows of a map,
+    /// so it doesn't need walls around it.
+    Widened,
+}
+
+/// checks the map before it's turned into a warehouse, so every problem is
+/// reported with its line and column, counting from 1 within the map
+pub(crate) fn validate(map: &str, format: Format) -> Result<(), Part2StateParseError> {
+    let lines: Vec<Vec<char>> = map
+        .trim()
+        .lines()
+        .map(|line| line.trim().chars().collect())
+        .collect();
+    let expected = lines.first().map_or(0, Vec::len);
+    let mut robot = None;
+
+    for (y, tiles) in lines.iter().enumerate() {
+        let line = y + 1;
+
+        if tiles.len() != expected {
+            return Err(NotRectangular {
+                line,
+                expected,
+                found: tiles.len(),
+            });
+        }
+
+        for (x, tile) in tiles.iter().copied().enumerate() {
+            let column = x + 1;
+            let previous = x.checked_sub(1).map(|x| tiles[x]);
+            let next = tiles.get(x + 1).copied();
+
+            match (format, tile) {
+                (_, '#' | '.') => {}
+                (_, '@') => match robot {
+                    None => robot = Some((line, column)),
+                    Some(first) => {
+                        return Err(MultipleRobots {
+                            line,
+                            column,
+                            first,
+                        });
+                    }
+                },
+                (Format::Puzzle, 'O') => {}
+                (Format::Widened, '[') if next != Some(']') => {
+                    return Err(UnclosedBox { line, column });
+                }
+                (Format::Widened, ']') if previous != Some('[') => {
+                    return Err(UnopenedBox { line, column });
+                }
+                (Format::Widened, '[' | ']') => {}
+                _ => return Err(InvalidTile { tile, line, column }),
+            }
+
+            let is_border = y == 0 || line == lines.len() || x == 0 || column == expected;
+            if format == Format::Puzzle && is_border && tile != '#' {
+                return Err(NotWallBordered { tile, line, column });
+            }
+        }
+    }
+
+    match robot {
+        Some(_) => Ok(()),
+        None => Err(NoRobotFound),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_puzzle_examples() {
+        for path in [
+            "./input.txt",
+            "./input_example.txt",
+            "./input_example_small.txt",
+        ] {
+            let input = std::fs::read_to_string(path).unwrap();
+            let (map, _) = input.split_once("\n\n").unwrap();
+
+            assert!(validate(map, Format::Puzzle).is_ok(), "{path}");
+        }
+    }
+
+    #[test]
+    fn test_robots() {
+        let result = validate("#####\n#@.@#\n#####", Format::Puzzle);
+        assert!(matches!(
+            result,
+            Err(MultipleRobots {
+                line: 2,
+                column: 4,
+                first: (2, 2)
+            })
+        ));
+
+        let result = validate("####\n#.O#\n####", Format::Puzzle);
+        assert!(matches!(result, Err(NoRobotFound)));
+    }
+
+    #[test]
+    fn test_not_rectangular() {
+        let result = validate("#####\n#@.#\n#####", Format::Puzzle);
+
+        assert!(matches!(
+            result,
+            Err(NotRectangular {
+                line: 2,
+                expected: 5,
+                found: 4
+            })
+        ));
+    }
+
+    #[test]
+    fn test_not_wall_bordered() {
+        let result = validate("####\n#@..\n####", Format::Puzzle);
+        assert!(matches!(
+            result,
+            Err(NotWallBordered {
+                tile: '.',
+                line: 2,
+                column: 4
+            })
+        ));
+
+        assert!(validate("..@.", Format::Widened).is_ok());
+    }
+
+    #[test]
+    fn test_boxes() {
+        assert!(matches!(
+            validate(".[.@", Format::Widened),
+            Err(UnclosedBox { line: 1, column: 2 })
+        ));
+        assert!(matches!(
+            validate("@..\n[[]", Format::Widened),
+            Err(UnclosedBox { line: 2, column: 1 })
+        ));
+        assert!(matches!(
+            validate("@.]", Format::Widened),
+            Err(UnopenedBox { line: 1, column: 3 })
+        ));
+        assert!(matches!(
+            validate("@O", Format::Widened),
+            Err(InvalidTile {
+                tile: 'O',
+                line: 1,
+                column: 2
+            })
+        ));
+        assert!(matches!(
+            validate("#####\n#@[]#\n#####", Format::Puzzle),
+            Err(InvalidTile {
+                tile: '[',
+                line: 2,
+                column: 3
+            })
+        ));
+    }
+}
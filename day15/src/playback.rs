@@ -0,0 +1,402 @@
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+use crossterm::event::{self, Event as TerminalEvent, KeyCode, KeyEventKind};
+use crossterm::{cursor, execute, terminal};
+use thiserror::Error;
+
+use crate::part_2_state::{Part2State, Part2StateParseError};
+use crate::replay::Replay;
+
+const MAX_FPS: u32 = 1000;
+
+pub const CONTROLS: &str = "space pause, ←/→ step, home/end, +/- speed, digits then g jump, q quit";
+
+/// how a run is played back
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PlaybackOptions {
+    /// steps shown per second
+    pub fps: u32,
+    /// the step to start from
+    pub from: usize,
+    /// the step to stop at, or the end of the run
+    pub to: Option<usize>,
+}
+
+impl Default for PlaybackOptions {
+    fn default() -> Self {
+        PlaybackOptions {
+            fps: 30,
+            from: 0,
+            to: None,
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum PlaybackError {
+    #[error("Failed to parse state")]
+    ParseError(#[from] Part2StateParseError),
+
+    #[error("Terminal Error: {0}")]
+    Io(#[from] io::Error),
+}
+
+/// a key pressed while playing
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Control {
+    TogglePause,
+    Forward,
+    Backward,
+    Start,
+    End,
+    Faster,
+    Slower,
+    Digit(usize),
+    Jump,
+    Quit,
+}
+
+use Control::*;
+
+impl Control {
+    fn from_key(key: KeyCode) -> Option<Control> {
+        match key {
+            KeyCode::Char(' ') => Some(TogglePause),
+            KeyCode::Right | KeyCode::Char('l') => Some(Forward),
+            KeyCode::Left | KeyCode::Char('h') => Some(Backward),
+            KeyCode::Home => Some(Start),
+            KeyCode::End => Some(End),
+            KeyCode::Char('+') | KeyCode::Char('=') => Some(Faster),
+            KeyCode::Char('-') => Some(Slower),
+            KeyCode::Char(c) if c.is_ascii_digit() => c.to_digit(10).map(|d| Digit(d as usize)),
+            KeyCode::Char('g') | KeyCode::Enter => Some(Jump),
+            KeyCode::Char('q') | KeyCode::Esc => Some(Quit),
+            _ => None,
+        }
+    }
+}
+
+/// the state of a playback, separate from the terminal so it can be driven by
+/// a list of controls
+pub(crate) struct Player {
+    replay: Replay,
+    end: usize,
+    fps: u32,
+    paused: bool,
+    /// the step number typed so far, jumped to by `Jump`
+    typed: Option<usize>,
+    quit: bool,
+}
+
+impl Player {
+    pub(crate) fn new(state: &Part2State, options: PlaybackOptions) -> Player {
+        let mut replay = state.replay();
+        let end = options.to.unwrap_or(replay.len()).min(replay.len());
+        replay.seek(options.from.min(end));
+
+        Player {
+            replay,
+            end,
+            fps: options.fps.clamp(1, MAX_FPS),
+            paused: false,
+            typed: None,
+            quit: false,
+        }
+    }
+
+    pub(crate) fn frame_duration(&self) -> Duration {
+        Duration::from_secs(1) / self.fps
+    }
+
+    /// moves on a step unless paused, pausing at the end
+    pub(crate) fn tick(&mut self) {
+        if self.paused {
+            return;
+        }
+
+        match self.replay.position() < self.end {
+            true => {
+                self.replay.forward();
+            }
+            false => self.paused = true,
+        }
+    }
+
+    pub(crate) fn handle(&mut self, control: Control) {
+        if !matches!(control, Digit(_)) && control != Jump {
+            self.typed = None;
+        }
+
+        match control {
+            TogglePause => self.paused = !self.paused,
+            Forward => {
+                self.paused = true;
+                if self.replay.position() < self.end {
+                    self.replay.forward();
+                }
+            }
+            Backward => {
+                self.paused = true;
+                self.replay.backward();
+            }
+            Start => self.replay.seek(0),
+            End => self.replay.seek(self.end),
+            Faster => self.fps = (self.fps * 2).min(MAX_FPS),
+            Slower => self.fps = (self.fps / 2).max(1),
+            Digit(digit) => {
+                let typed = self.typed.unwrap_or(0).saturating_mul(10);
+                self.typed = Some(typed.saturating_add(digit));
+            }
+            Jump => {
+                if let Some(step) = self.typed.take() {
+                    self.replay.seek(step.min(self.end));
+                }
+            }
+            Quit => self.quit = true,
+        }
+    }
+
+    /// a status line, then the map
+    pub(crate) fn frame(&self) -> Vec<String> {
+        let position = self.replay.position();
+        let instruction = position
+            .checked_sub(1)
+            .and_then(|previous| self.replay.instruction(previous))
+            .map_or(' ', char::from);
+        let paused = match self.paused {
+            true => " paused",
+            false => "",
+        };
+        let typed = self
+            .typed
+            .map_or("".to_string(), |typed| format!(" go to {typed}"));
+
+        let status = format!(
+            "step {position}/{} {instruction} score {} {}fps{paused}{typed}",
+            self.end,
+            self.replay.warehouse().score(),
+            self.fps,
+        );
+
+        std::iter::once(status)
+            .chain(
+                self.replay
+                    .warehouse()
+                    .to_string()
+                    .lines()
+                    .map(String::from),
+            )
+            .collect()
+    }
+}
+
+/// the escape codes that turn the `previous` frame into `next`, rewriting only
+/// the lines that changed
+pub(crate) fn redraw(previous: &[String], next: &[String]) -> String {
+    next.iter()
+        .enumerate()
+        .filter(|(row, line)| previous.get(*row) != Some(line))
+        .map(|(row, line)| format!("\x1b[{};1H{line}\x1b[K", row + 1))
+        .collect()
+}
+
+/// plays part 2 in the terminal, redrawing the map in place every step
+pub fn play(input: &str, options: PlaybackOptions) -> Result<(), PlaybackError> {
+    let state: Part2State = input.parse()?;
+    let mut player = Player::new(&state, options);
+    let mut out = io::stdout();
+
+    terminal::enable_raw_mode()?;
+    execute!(out, terminal::EnterAlternateScreen, cursor::Hide)?;
+
+    let result = run(&mut player, &mut out);
+
+    execute!(out, cursor::Show, terminal::LeaveAlternateScreen)?;
+    terminal::disable_raw_mode()?;
+
+    Ok(result?)
+}
+
+fn run(player: &mut Player, out: &mut impl Write) -> io::Result<()> {
+    let mut shown = vec![];
+    let mut deadline = Instant::now();
+
+    while !player.quit {
+        let frame = player.frame();
+        if shown.is_empty() {
+            write!(out, "\x1b[{};1H{CONTROLS}", frame.len() + 2)?;
+        }
+        write!(out, "{}", redraw(&shown, &frame))?;
+        out.flush()?;
+        shown = frame;
+
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if event::poll(remaining)? {
+            if let TerminalEvent::Key(key) = event::read()?
+                && key.kind == KeyEventKind::Press
+                && let Some(control) = Control::from_key(key.code)
+            {
+                player.handle(control);
+            }
+            continue;
+        }
+
+        player.tick();
+        deadline = Instant::now() + player.frame_duration();
+    }
+
+    Ok(())
+}
+
+/// records part 2's playback as an asciicast v2 file, one event per step, that
+/// only rewrites the lines that changed
+pub fn asciicast(input: &str, options: PlaybackOptions) -> Result<String, Part2StateParseError> {
+    let state: Part2State = input.parse()?;
+    let mut player = Player::new(&state, options);
+    let seconds = 1.0 / player.fps as f64;
+
+    let first = player.frame();
+    let width = first
+        .iter()
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or(0);
+    let mut cast = format!(
+        "{{\"version\": 2, \"width\": {width}, \"height\": {}, \"title\": \"day15\"}}\n",
+        first.len()
+    );
+    cast += &cast_event(0.0, &format!("\x1b[2J{}", redraw(&[], &first)));
+
+    let mut shown = first;
+    let mut time = 0.0;
+    while player.replay.position() < player.end {
+        player.tick();
+        time += seconds;
+
+        let frame = player.frame();
+        cast += &cast_event(time, &redraw(&shown, &frame));
+        shown = frame;
+    }
+
+    Ok(cast)
+}
+
+fn cast_event(time: f64, output: &str) -> String {
+    format!("[{time:.6}, \"o\", \"{}\"]\n", escape_json(output))
+}
+
+fn escape_json(s: &str) -> String {
+    s.chars()
+        .map(|c| match c {
+            '"' => "\\\"".to_string(),
+            '\\' => "\\\\".to_string(),
+            '\n' => "\\n".to_string(),
+            c if c.is_control() => format!("\\u{:04x}", c as u32),
+            c => c.to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn player(options: PlaybackOptions) -> Player {
+        let state: Part2State = "
+            #####
+            #.O@#
+            #####
+
+            <<><
+        "
+        .parse()
+        .unwrap();
+
+        Player::new(&state, options)
+    }
+
+    #[test]
+    fn test_tick_pauses_at_the_end() {
+        let mut sut = player(PlaybackOptions {
+            to: Some(2),
+            ..Default::default()
+        });
+
+        for _ in 0..5 {
+            sut.tick();
+        }
+
+        assert_eq!(sut.replay.position(), 2);
+        assert!(sut.paused);
+        assert_eq!(sut.frame()[0], "step 2/2 < score 102 30fps paused");
+    }
+
+    #[test]
+    fn test_controls() {
+        let mut sut = player(PlaybackOptions::default());
+
+        sut.handle(Forward);
+        sut.handle(Forward);
+        sut.handle(Backward);
+        assert_eq!(sut.replay.position(), 1);
+        assert!(sut.paused);
+
+        sut.handle(Digit(3));
+        sut.handle(Jump);
+        assert_eq!(sut.replay.position(), 3);
+
+        sut.handle(Digit(9));
+        sut.handle(Digit(9));
+        sut.handle(Jump);
+        assert_eq!(sut.replay.position(), 4);
+
+        sut.handle(Start);
+        assert_eq!(sut.replay.position(), 0);
+
+        sut.handle(Slower);
+        sut.handle(Slower);
+        assert_eq!(sut.fps, 7);
+        sut.handle(Quit);
+        assert!(sut.quit);
+    }
+
+    #[test]
+    fn test_redraw_only_changed_lines() {
+        let previous = vec!["a".to_string(), "b".to_string()];
+        let next = vec!["a".to_string(), "c".to_string(), "d".to_string()];
+
+        assert_eq!(redraw(&previous, &next), "\x1b[2;1Hc\x1b[K\x1b[3;1Hd\x1b[K");
+    }
+
+    #[test]
+    fn test_asciicast() {
+        let input = "
+            #####
+            #.O@#
+            #####
+
+            <<
+        ";
+        let result = asciicast(
+            input,
+            PlaybackOptions {
+                fps: 10,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let lines: Vec<_> = result.lines().collect();
+
+        assert_eq!(
+            lines[0],
+            r#"{"version": 2, "width": 26, "height": 4, "title": "day15"}"#
+        );
+        assert!(lines[1].starts_with(r#"[0.000000, "o", "\u001b[2J\u001b[1;1Hstep 0/2"#));
+        assert_eq!(
+            lines[2],
+            r#"[0.100000, "o", "\u001b[1;1Hstep 1/2 < score 103 10fps\u001b[K\u001b[3;1H##.[]@..##\u001b[K"]"#
+        );
+        assert_eq!(lines.len(), 4);
+    }
+}
@@ -0,0 +1,240 @@
+use crate::instruction::Instruction;
+use crate::part_2_state::{Part2State, Part2StateParseError};
+use crate::warehouse::{BoxId, Warehouse};
+
+/// a run that can be stepped backwards as well as forwards. Every instruction
+/// that has been followed remembers which boxes it pushed, so undoing it just
+/// shifts them back, and redoing it doesn't have to look for walls again.
+#[derive(Clone, Debug)]
+pub(crate) struct Replay {
+    warehouse: Warehouse,
+    instructions: Vec<Instruction>,
+    /// the boxes pushed by each instruction followed so far, None if it was
+    /// blocked
+    history: Vec<Option<Vec<BoxId>>>,
+    /// how many instructions have been followed to reach `warehouse`
+    position: usize,
+}
+
+impl Replay {
+    pub(crate) fn new(warehouse: Warehouse, instructions: Vec<Instruction>) -> Replay {
+        Replay {
+            warehouse,
+            instructions,
+            history: vec![],
+            position: 0,
+        }
+    }
+
+    pub(crate) fn warehouse(&self) -> &Warehouse {
+        &self.warehouse
+    }
+
+    pub(crate) fn position(&self) -> usize {
+        self.position
+    }
+
+    /// the number of instructions, so positions run from 0 to len inclusive
+    pub(crate) fn len(&self) -> usize {
+        self.instructions.len()
+    }
+
+    /// the instruction followed to get from `position` to `position + 1`
+    pub(crate) fn instruction(&self, position: usize) -> Option<Instruction> {
+        self.instructions.get(position).copied()
+    }
+
+    /// the instruction `forward` would follow next
+    pub(crate) fn next_instruction(&self) -> Option<Instruction> {
+        self.instruction(self.position)
+    }
+
+    /// follows the next instruction, returning false if there are none left
+    pub(crate) fn forward(&mut self) -> bool {
+        let Some(instruction) = self.next_instruction() else {
+            return false;
+        };
+
+        match self.history.get(self.position) {
+            Some(Some(pushed)) => self.warehouse.shift(pushed, instruction),
+            Some(None) => {}
+            None => {
+                let pushed = self.warehouse.step(instruction);
+                self.history.push(pushed);
+            }
+        }
+
+        self.position += 1;
+        true
+    }
+
+    /// follows a new instruction from here, dropping any instructions after
+    /// this position, and returns whether the robot moved
+    pub(crate) fn follow(&mut self, instruction: Instruction) -> bool {
+        self.instructions.truncate(self.position);
+        self.history.truncate(self.position);
+        self.instructions.push(instruction);
+        self.forward();
+
+        self.history[self.position - 1].is_some()
+    }
+
+    /// the instructions followed to get to this position
+    pub(crate) fn followed(&self) -> &[Instruction] {
+        &self.instructions[..self.position]
+    }
+
+    /// undoes the last instruction followed, returning false if back at the
+    /// start
+    pub(crate) fn backward(&mut self) -> bool {
+        let Some(position) = self.position.checked_sub(1) else {
+            return false;
+        };
+
+        if let Some(pushed) = &self.history[position] {
+            self.warehouse
+                .shift(pushed, self.instructions[position].opposite());
+        }
+
+        self.position = position;
+        true
+    }
+
+    /// steps forwards or backwards until `position` instructions have been
+    /// followed, stopping at the last one if there aren't that many
+    pub(crate) fn seek(&mut self, position: usize) {
+        while self.position < position && self.forward() {}
+        while self.position > position && self.backward() {}
+    }
+
+    /// the first position where `is_valid` fails, or None if it holds all the
+    /// way to the end. Bisects, so it expects the map to stay invalid once it
+    /// goes wrong. Leaves the replay at that position, or at the end.
+    pub(crate) fn bisect(&mut self, is_valid: impl Fn(&Warehouse) -> bool) -> Option<usize> {
+        self.seek(self.len());
+        if is_valid(&self.warehouse) {
+            return None;
+        }
+
+        // the map is invalid at high, and valid before low
+        let (mut low, mut high) = (0, self.len());
+        while low < high {
+            let middle = (low + high) / 2;
+            self.seek(middle);
+
+            match is_valid(&self.warehouse) {
+                true => low = middle + 1,
+                false => high = middle,
+            }
+        }
+
+        self.seek(high);
+        Some(high)
+    }
+}
+
+/// where a part 2 run first leaves the map invalid
+#[derive(Debug, Eq, PartialEq)]
+pub struct Bisection {
+    /// how many instructions had been followed, 0 if the map starts invalid
+    pub step: usize,
+    /// the last instruction followed
+    pub instruction: Option<char>,
+    pub map: String,
+}
+
+/// runs part 2 and finds the first instruction after which a box or the robot
+/// overlaps a wall or another box, or None if the map stays valid
+pub fn bisect(input: &str) -> Result<Option<Bisection>, Part2StateParseError> {
+    let state: Part2State = input.parse()?;
+    let mut replay = state.replay();
+
+    let bisection = replay.bisect(Warehouse::is_valid).map(|step| Bisection {
+        step,
+        instruction: step
+            .checked_sub(1)
+            .and_then(|previous| replay.instruction(previous))
+            .map(char::from),
+        map: replay.warehouse().to_string(),
+    });
+
+    Ok(bisection)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn replay(map: &str, instructions: &str) -> Replay {
+        let instructions = instructions
+            .chars()
+            .map(|c| c.try_into().unwrap())
+            .collect();
+
+        Replay::new(Warehouse::parse(map, 2).unwrap(), instructions)
+    }
+
+    #[test]
+    fn test_backward_restores_every_state() {
+        let input = std::fs::read_to_string("./input_example.txt").unwrap();
+        let (map, instructions) = input.split_once("\n\n").unwrap();
+        let mut sut = replay(map, &instructions.replace('\n', ""));
+
+        let mut states = vec![sut.warehouse().clone()];
+        while sut.forward() {
+            states.push(sut.warehouse().clone());
+        }
+        assert_eq!(sut.position(), sut.len());
+        assert_eq!(sut.warehouse().score(), 9021);
+
+        while sut.backward() {
+            assert_eq!(sut.warehouse(), &states[sut.position()]);
+        }
+        assert_eq!(sut.position(), 0);
+
+        sut.seek(400);
+        assert_eq!(sut.warehouse(), &states[400]);
+    }
+
+    #[test]
+    fn test_seek_past_the_end() {
+        let mut sut = replay("#.@#", "<<");
+
+        sut.seek(10);
+
+        assert_eq!(sut.position(), 2);
+        assert_eq!(sut.warehouse().to_string(), "##@...##\n");
+    }
+
+    #[test]
+    fn test_follow_drops_undone_instructions() {
+        let mut sut = replay("#..@#", "<<");
+        sut.forward();
+        sut.backward();
+
+        assert!(sut.follow(Instruction::E));
+        assert!(!sut.follow(Instruction::E));
+        assert_eq!(sut.len(), 2);
+        assert_eq!(sut.followed(), [Instruction::E, Instruction::E]);
+        assert!(!sut.forward());
+    }
+
+    #[test]
+    fn test_bisect() {
+        let mut sut = replay("#....@#", "<<<<<<");
+
+        // the robot reaches x 6 after 4 instructions and stays left of it
+        let result = sut.bisect(|warehouse| warehouse.robot().0 > 6);
+
+        assert_eq!(result, Some(4));
+        assert_eq!(sut.position(), 4);
+        assert_eq!(sut.bisect(Warehouse::is_valid), None);
+    }
+
+    #[test]
+    fn test_bisect_input() {
+        let input = std::fs::read_to_string("./input.txt").unwrap();
+
+        assert_eq!(bisect(&input).unwrap(), None);
+    }
+}
@@ -0,0 +1,89 @@
+use std::fmt::Display;
+
+use crate::instruction::Instruction;
+use crate::warehouse::Warehouse;
+
+/// a run of the instructions on a single copy of the map, stepping forwards
+/// one instruction at a time
+pub struct Steps<'a> {
+    warehouse: Warehouse,
+    instructions: &'a [Instruction],
+}
+
+/// the map and the instructions still to follow, partway through a run
+#[derive(Clone, Copy, Debug)]
+pub struct Snapshot<'a> {
+    warehouse: &'a Warehouse,
+    instructions: &'a [Instruction],
+}
+
+impl<'a> Steps<'a> {
+    pub(crate) fn new(warehouse: Warehouse, instructions: &'a [Instruction]) -> Self {
+        Steps {
+            warehouse,
+            instructions,
+        }
+    }
+
+    /// follows the next instruction, or returns None once there are none left
+    pub fn step(&mut self) -> Option<Snapshot<'_>> {
+        let (instruction, rest) = self.instructions.split_first()?;
+        self.warehouse.step(*instruction);
+        self.instructions = rest;
+
+        Some(self.snapshot())
+    }
+
+    /// where the run is now, without following anything
+    pub fn snapshot(&self) -> Snapshot<'_> {
+        Snapshot {
+            warehouse: &self.warehouse,
+            instructions: self.instructions,
+        }
+    }
+}
+
+impl Snapshot<'_> {
+    pub fn score(&self) -> usize {
+        self.warehouse.score()
+    }
+
+    /// how many instructions are still to follow
+    pub fn remaining(&self) -> usize {
+        self.instructions.len()
+    }
+}
+
+/// the same as the state it came from would be shown
+impl Display for Snapshot<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", self.warehouse)?;
+
+        for instruction in self.instructions {
+            write!(f, "{instruction}")?;
+        }
+
+        writeln!(f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_steps() {
+        let warehouse = Warehouse::parse(".O.@", 1).unwrap();
+        let instructions = [Instruction::W, Instruction::W, Instruction::W];
+        let mut sut = Steps::new(warehouse, &instructions);
+
+        assert_eq!(sut.snapshot().to_string(), ".O.@\n\n<<<\n");
+        assert_eq!(sut.step().unwrap().to_string(), ".O@.\n\n<<\n");
+        assert_eq!(sut.step().unwrap().to_string(), "O@..\n\n<\n");
+
+        let last = sut.step().unwrap();
+        assert_eq!(last.to_string(), "O@..\n\n\n");
+        assert_eq!(last.remaining(), 0);
+        assert!(sut.step().is_none());
+    }
+}
@@ -0,0 +1,410 @@
+use std::fmt::Display;
+
+use aoc_grid::{Grid, XY};
+use thiserror::Error;
+
+use crate::instruction::Instruction;
+
+/// index of a box in `Warehouse::boxes`, which stays the same however far
+/// the box is pushed
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub(crate) struct BoxId(pub(crate) usize);
+
+/// a box covering `width` cells to the right of its left edge
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub(crate) struct WarehouseBox {
+    pub(crate) xy: XY,
+    pub(crate) width: usize,
+}
+
+impl WarehouseBox {
+    pub(crate) fn cells(&self) -> impl Iterator<Item = XY> + use<> {
+        let XY(x, y) = self.xy;
+        (x..x + self.width).map(move |x| XY(x, y))
+    }
+
+    /// the box's GPS coordinate, measured from its left edge
+    pub(crate) fn gps(&self) -> usize {
+        let XY(x, y) = self.xy;
+        x + (100 * y)
+    }
+}
+
+/// what's on a cell of the map, apart from the robot
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum Cell {
+    Empty,
+    Wall,
+    Box(BoxId),
+}
+
+/// the map for either part. Boxes are objects that can be any number of cells
+/// wide, so part 1's boxes are 1 wide and part 2's are 2 wide, and both are
+/// pushed by the same code.
+#[derive(Clone, Debug)]
+pub(crate) struct Warehouse {
+    /// walls and the box covering each cell, so finding what's in the way is
+    /// an index into a Vec
+    cells: Grid<Cell>,
+    boxes: Vec<WarehouseBox>,
+    robot: XY,
+}
+
+#[derive(Debug, Error)]
+pub enum WarehouseParseError {
+    #[error("Invalid tile: {0}")]
+    InvalidTile(char),
+
+    #[error("No Robot found on map")]
+    NoRobotFound,
+}
+
+use WarehouseParseError::*;
+
+impl Warehouse {
+    /// parses a puzzle map, stretching every tile `box_width` cells wide, so
+    /// boxes are `box_width` wide and the robot stays 1 wide
+    pub(crate) fn parse(map: &str, box_width: usize) -> Result<Warehouse, WarehouseParseError> {
+        let stretched = map
+            .trim()
+            .lines()
+            .map(|line| {
+                line.trim()
+                    .chars()
+                    .map(|c| match c {
+                        '#' => Ok("#".repeat(box_width)),
+                        'O' => Ok(render_box(box_width)),
+                        '@' => Ok(format!("@{}", ".".repeat(box_width - 1))),
+                        '.' => Ok(".".repeat(box_width)),
+                        _ => Err(InvalidTile(c)),
+                    })
+                    .collect::<Result<String, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .join("\n");
+
+        Warehouse::parse_stretched(&stretched)
+    }
+
+    /// parses a map the way it is displayed, with `O` for a box 1 wide and
+    /// `[`, `-` and `]` for wider ones
+    pub(crate) fn parse_stretched(map: &str) -> Result<Warehouse, WarehouseParseError> {
+        let mut walls = vec![];
+        let mut boxes = vec![];
+        let mut robot = None;
+        let (mut width, mut height) = (0, 0);
+
+        for (y, line) in map.trim().lines().enumerate() {
+            let mut open = None;
+
+            for (x, c) in line.trim().chars().enumerate() {
+                match (c, open) {
+                    ('#', None) => walls.push(XY(x, y)),
+                    ('@', None) => robot = Some(XY(x, y)),
+                    ('.', None) => {}
+                    ('O', None) => boxes.push(WarehouseBox {
+                        xy: XY(x, y),
+                        width: 1,
+                    }),
+                    ('[', None) => open = Some(x),
+                    ('-', Some(_)) => {}
+                    (']', Some(left)) => {
+                        boxes.push(WarehouseBox {
+                            xy: XY(left, y),
+                            width: x - left + 1,
+                        });
+                        open = None;
+                    }
+                    _ => return Err(InvalidTile(c)),
+                }
+                width = width.max(x + 1);
+            }
+
+            if open.is_some() {
+                return Err(InvalidTile('['));
+            }
+            height = y + 1;
+        }
+
+        let robot = robot.ok_or(NoRobotFound)?;
+        let mut cells = Grid::new(width, height, Cell::Empty);
+        for xy in walls {
+            cells[xy] = Cell::Wall;
+        }
+        for (id, b) in boxes.iter().enumerate() {
+            for xy in b.cells() {
+                cells[xy] = Cell::Box(BoxId(id));
+            }
+        }
+
+        Ok(Warehouse {
+            cells,
+            boxes,
+            robot,
+        })
+    }
+
+    pub(crate) fn robot(&self) -> XY {
+        self.robot
+    }
+
+    pub(crate) fn boxes(&self) -> &[WarehouseBox] {
+        &self.boxes
+    }
+
+    /// the sum of every box's GPS coordinate
+    pub(crate) fn score(&self) -> usize {
+        self.boxes.iter().map(WarehouseBox::gps).sum()
+    }
+
+    /// the cell one step from xy, or None if that leaves the map
+    fn neighbor(&self, xy: XY, instruction: Instruction) -> Option<XY> {
+        self.cells.step(xy, instruction.into())
+    }
+
+    /// every box the robot would push by following the instruction, or None
+    /// if a wall (or the edge of the map) is in the way. A box pushes every
+    /// box in front of any of its cells, so wide boxes can fan out.
+    pub(crate) fn pushed_boxes(&self, instruction: Instruction) -> Option<Vec<BoxId>> {
+        let mut pushed = vec![];
+        let mut frontier = vec![self.robot];
+
+        while let Some(xy) = frontier.pop() {
+            let next = self.neighbor(xy, instruction)?;
+
+            match self.cells[next] {
+                Cell::Wall => return None,
+                Cell::Box(id) if !pushed.contains(&id) => {
+                    pushed.push(id);
+                    frontier.extend(self.boxes[id.0].cells());
+                }
+                _ => {}
+            }
+        }
+
+        Some(pushed)
+    }
+
+    /// follows the instruction, returning the boxes that were pushed, or None
+    /// if the robot is blocked and nothing moved
+    pub(crate) fn step(&mut self, instruction: Instruction) -> Option<Vec<BoxId>> {
+        let pushed = self.pushed_boxes(instruction)?;
+        self.shift(&pushed, instruction);
+
+        Some(pushed)
+    }
+
+    /// moves the robot and the given boxes one cell without checking what's
+    /// in the way, so it can replay a step that was already checked, or undo
+    /// one by shifting the same boxes the opposite way
+    pub(crate) fn shift(&mut self, pushed: &[BoxId], instruction: Instruction) {
+        for id in pushed.iter() {
+            for xy in self.boxes[id.0].cells() {
+                self.cells[xy] = Cell::Empty;
+            }
+        }
+
+        for id in pushed.iter() {
+            let xy = self
+                .neighbor(self.boxes[id.0].xy, instruction)
+                .expect("shifted a box off the map");
+            self.boxes[id.0].xy = xy;
+            for xy in self.boxes[id.0].cells() {
+                self.cells[xy] = Cell::Box(*id);
+            }
+        }
+
+        self.robot = self
+            .neighbor(self.robot, instruction)
+            .expect("shifted the robot off the map");
+    }
+
+    /// whether everything is on the map and nothing overlaps: the robot is on
+    /// an empty cell, and every cell a box covers is marked as that box and
+    /// no other cells are
+    pub(crate) fn is_valid(&self) -> bool {
+        let robot_is_free = self.cells.get(self.robot) == Some(&Cell::Empty);
+        let boxes_are_marked = self.boxes.iter().enumerate().all(|(id, b)| {
+            b.cells()
+                .all(|xy| xy != self.robot && self.cells.get(xy) == Some(&Cell::Box(BoxId(id))))
+        });
+        let marked = self
+            .cells
+            .iter()
+            .filter(|(_, cell)| matches!(cell, Cell::Box(_)))
+            .count();
+        let covered: usize = self.boxes.iter().map(|b| b.width).sum();
+
+        robot_is_free && boxes_are_marked && marked == covered
+    }
+
+    /// the character displayed for a cell
+    pub(crate) fn tile(&self, xy: XY) -> char {
+        if self.robot == xy {
+            return '@';
+        }
+
+        match self.cells.get(xy) {
+            None | Some(Cell::Empty) => '.',
+            Some(Cell::Wall) => '#',
+            Some(Cell::Box(id)) => {
+                let b = self.boxes[id.0];
+                render_box(b.width)
+                    .chars()
+                    .nth(xy.0 - b.xy.0)
+                    .unwrap_or('.')
+            }
+        }
+    }
+}
+
+/// `O` for a box 1 wide, otherwise `[]` with a `-` for every cell in between
+fn render_box(width: usize) -> String {
+    match width {
+        1 => "O".to_string(),
+        _ => format!("[{}]", "-".repeat(width.saturating_sub(2))),
+    }
+}
+
+/// compared by what's displayed rather than by box id, since the same map can
+/// be reached by pushing boxes in a different order
+impl PartialEq for Warehouse {
+    fn eq(&self, other: &Self) -> bool {
+        self.robot == other.robot && self.to_string() == other.to_string()
+    }
+}
+
+impl Eq for Warehouse {}
+
+impl Display for Warehouse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in 0..self.cells.height() {
+            for x in 0..self.cells.width() {
+                f.write_fmt(format_args!("{}", self.tile(XY(x, y))))?;
+            }
+
+            f.write_fmt(format_args!("\n"))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use Instruction::*;
+
+    #[test]
+    fn test_parse_stretches_tiles() {
+        let input = "
+            #@
+            .O
+        ";
+
+        assert_eq!(Warehouse::parse(input, 1).unwrap().to_string(), "#@\n.O\n");
+        assert_eq!(
+            Warehouse::parse(input, 2).unwrap().to_string(),
+            "##@.\n..[]\n"
+        );
+        assert_eq!(
+            Warehouse::parse(input, 3).unwrap().to_string(),
+            "###@..\n...[-]\n"
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(matches!(Warehouse::parse("#x@", 1), Err(InvalidTile('x'))));
+        assert!(matches!(
+            Warehouse::parse_stretched("[-@"),
+            Err(InvalidTile('@'))
+        ));
+        assert!(matches!(
+            Warehouse::parse_stretched("@]"),
+            Err(InvalidTile(']'))
+        ));
+        assert!(matches!(Warehouse::parse("#.", 1), Err(NoRobotFound)));
+    }
+
+    #[test]
+    fn test_step_blocked_by_edge() {
+        let mut sut = Warehouse::parse_stretched("O@").unwrap();
+
+        assert_eq!(sut.step(W), None);
+        assert_eq!(sut.to_string(), "O@\n");
+    }
+
+    #[test]
+    fn test_step_triple_wide() {
+        let mut sut = Warehouse::parse_stretched(
+            "
+            .......
+            .[-][-]
+            ..[-]..
+            ...@...
+        ",
+        )
+        .unwrap();
+
+        assert_eq!(sut.step(N), Some(vec![BoxId(2), BoxId(1), BoxId(0)]));
+        assert_eq!(sut.to_string(), ".[-][-]\n..[-]..\n...@...\n.......\n");
+        assert_eq!(sut.step(N), None);
+    }
+
+    #[test]
+    fn test_shift_undoes_step() {
+        let original = Warehouse::parse_stretched(
+            "
+            .......
+            .[-][-]
+            ..[-]..
+            ...@...
+        ",
+        )
+        .unwrap();
+        let mut sut = original.clone();
+
+        let pushed = sut.step(N).unwrap();
+        sut.shift(&pushed, N.opposite());
+
+        assert_eq!(sut, original);
+        assert_eq!(sut.cells, original.cells);
+    }
+
+    #[test]
+    fn test_is_valid() {
+        let mut sut = Warehouse::parse_stretched("#[]@").unwrap();
+        assert!(sut.is_valid());
+
+        sut.boxes[0].xy = XY(0, 0);
+        assert!(!sut.is_valid());
+
+        sut.boxes[0].xy = XY(2, 0);
+        assert!(!sut.is_valid());
+    }
+
+    #[test]
+    fn test_example_in_every_width() {
+        let input = std::fs::read_to_string("./input_example.txt").unwrap();
+        let (map, instructions) = input.split_once("\n\n").unwrap();
+        let instructions: Vec<Instruction> = instructions
+            .lines()
+            .flat_map(|line| line.chars())
+            .map(|c| c.try_into().unwrap())
+            .collect();
+
+        for box_width in 1..=3 {
+            let mut sut = Warehouse::parse(map, box_width).unwrap();
+            let count = sut.boxes.len();
+
+            for instruction in instructions.iter() {
+                sut.step(*instruction);
+            }
+
+            // boxes never merge or overlap however wide they are
+            assert_eq!(sut.boxes.len(), count);
+            assert!(sut.is_valid());
+        }
+    }
+}
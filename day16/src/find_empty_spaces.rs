@@ -1,66 +1,75 @@
-use std::collections::HashSet;
+use std::convert::Infallible;
 
-use crate::xy::XY;
+use aoc_grid::{Grid, GridParseError};
 
-pub(crate) fn find_empty_spaces(input: &str) -> HashSet<XY> {
-    input
-        .trim()
-        .lines()
-        .enumerate()
-        .filter(|(_, l)| *l != "#")
-        .flat_map(|(y, line)| {
-            line.trim()
-                .chars()
-                .enumerate()
-                .filter(|(_x, c)| *c != '#')
-                .map(move |(x, _c)| XY::new(x, y))
-        })
-        .collect()
+/// every tile of the map, true where it isn't a wall
+pub(crate) fn find_empty_spaces(input: &str) -> Result<Grid<bool>, GridParseError<Infallible>> {
+    Grid::parse(input, |c| Ok(c != '#'))
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use aoc_grid::XY;
+    use std::collections::HashSet;
+
+    fn empty_spaces(input: &str) -> HashSet<XY> {
+        find_empty_spaces(input)
+            .unwrap()
+            .iter()
+            .filter(|(_, empty)| **empty)
+            .map(|(xy, _)| xy)
+            .collect()
+    }
 
     #[test]
     fn test_empty_string() {
-        let result = find_empty_spaces("");
+        let result = empty_spaces("");
         assert!(result.is_empty(), "Expected an empty set: {:?}", result)
     }
 
     #[test]
     fn test_one_empty_space() {
-        let result = find_empty_spaces(".");
+        let result = empty_spaces(".");
         assert_eq!(result, HashSet::from([XY::new(0, 0)]));
     }
 
     #[test]
     fn test_one_wall() {
-        let result = find_empty_spaces("#");
+        let result = empty_spaces("#");
         assert!(result.is_empty(), "Expected an empty set: {:?}", result)
     }
 
     #[test]
     fn test_the_end() {
-        let result = find_empty_spaces("E");
+        let result = empty_spaces("E");
         assert_eq!(result, HashSet::from([XY::new(0, 0)]));
     }
 
     #[test]
     fn test_ignores_leading_whitespace() {
-        let result = find_empty_spaces(" E");
+        let result = empty_spaces(" E");
         assert_eq!(result, HashSet::from([XY::new(0, 0)]));
     }
 
     #[test]
     fn test_two_characters() {
-        let result = find_empty_spaces(".E");
+        let result = empty_spaces(".E");
         assert_eq!(result, HashSet::from([XY::new(0, 0), XY::new(1, 0)]));
     }
 
     #[test]
     fn test_two_lines_with_leading_whitespace() {
-        let result = find_empty_spaces(" .\n .");
+        let result = empty_spaces(" .\n .");
         assert_eq!(result, HashSet::from([XY::new(0, 0), XY::new(0, 1)]));
     }
+
+    #[test]
+    fn test_not_rectangular() {
+        let result = find_empty_spaces("#.#\n#.");
+        assert!(matches!(
+            result,
+            Err(GridParseError::NotRectangular { line: 2, .. })
+        ));
+    }
 }
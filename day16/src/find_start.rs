@@ -1,4 +1,4 @@
-use crate::xy::XY;
+use aoc_grid::XY;
 
 pub(crate) fn find_start(input: &str) -> Option<XY> {
     input.trim().lines().enumerate().find_map(|(y, line)| {
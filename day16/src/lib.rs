@@ -0,0 +1,189 @@
+mod find_empty_spaces;
+mod find_end;
+mod find_start;
+
+use std::collections::HashSet;
+use std::convert::Infallible;
+use thiserror::Error;
+
+use crate::find_empty_spaces::find_empty_spaces;
+use crate::find_end::find_end;
+use crate::find_start::find_start;
+
+use Direction::*;
+use aoc_grid::{Direction, GridParseError, XY};
+use aoc_search::{SearchResult, dijkstra};
+use aoc_solution::Solution;
+use std::error::Error;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    fn part_1(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        Ok(part_1(input)?.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        Ok(part_2(input)?.to_string())
+    }
+}
+
+#[derive(Debug, Error)]
+#[allow(clippy::enum_variant_names)]
+enum Day16Error {
+    #[error("Could not find E in input")]
+    NoEnd,
+
+    #[error("Could not find S in input")]
+    NoStart,
+
+    #[error("Could not find a path from S to E")]
+    NoPathToEnd,
+
+    #[error("Could not parse the map")]
+    InvalidMap(#[from] GridParseError<Infallible>),
+}
+
+use Day16Error::*;
+
+fn part_1(input: &str) -> Result<usize, Day16Error> {
+    let cheapest_paths = find_cheapest_paths(input)?;
+
+    cheapest_paths.goal_cost().ok_or(NoPathToEnd)
+}
+
+fn part_2(input: &str) -> Result<usize, Day16Error> {
+    let cheapest_paths = find_cheapest_paths(input)?;
+
+    if cheapest_paths.goals().is_empty() {
+        return Err(NoPathToEnd);
+    }
+
+    let tiles: HashSet<XY> = cheapest_paths
+        .on_optimal_paths()
+        .into_iter()
+        .map(|(xy, _direction)| xy)
+        .collect();
+
+    Ok(tiles.len())
+}
+
+fn trim_lines(s: &str) -> String {
+    s.trim()
+        .lines()
+        .map(|l| l.trim())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// the reindeer starts at S facing East. Moving forward costs 1 and turning a
+/// quarter turn in place costs 1000.
+fn find_cheapest_paths(input: &str) -> Result<SearchResult<(XY, Direction)>, Day16Error> {
+    let input = trim_lines(input);
+    let start = find_start(&input).ok_or(NoStart)?;
+    let end = find_end(&input).ok_or(NoEnd)?;
+    let empty_spaces = find_empty_spaces(&input)?;
+
+    let successors = |&(xy, direction): &(XY, Direction)| {
+        let forward = empty_spaces
+            .step(xy, direction)
+            .filter(|next| empty_spaces[*next])
+            .map(|next| ((next, direction), 1));
+
+        [
+            ((xy, direction.clockwise()), 1000),
+            ((xy, direction.counter_clockwise()), 1000),
+        ]
+        .into_iter()
+        .chain(forward)
+    };
+
+    Ok(dijkstra([(start, East)], successors, |(xy, _)| *xy == end))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs::read_to_string;
+
+    #[test]
+    fn test_part_1_simplest() {
+        let input = "####
+                     #SE#
+                     ####";
+        let result = part_1(input).unwrap(); // all it has to do is step forward
+
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn test_part_1_example() {
+        let input = read_to_string("./input_example.txt").unwrap();
+        let result = part_1(&input).unwrap();
+
+        assert_eq!(result, 7036);
+    }
+
+    #[test]
+    fn test_part_1_example_2() {
+        let input = read_to_string("./input_example_2.txt").unwrap();
+        let result = part_1(&input).unwrap();
+
+        assert_eq!(result, 11048);
+    }
+
+    #[test]
+    fn test_part_2_simplest() {
+        let input = "####
+                     #SE#
+                     ####";
+        let result = part_2(input).unwrap(); // all it has to do is step forward
+
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn test_part_2_one_path() {
+        // shortest is step forward, ccw, forward. cost: 1002
+        //
+        let input = "####
+                     #.E#
+                     #S.#
+                     ####";
+        let result = part_2(input).unwrap();
+
+        assert_eq!(result, 3); // (2,2), (3,2), (3,1)
+    }
+
+    #[test]
+    fn test_part_2_two_paths() {
+        // going either way around the wall should have equal cost: 3004
+        let input = "#####
+                     #...#
+                     #S#E#
+                     #...#
+                     #####";
+        let result = part_2(input).unwrap();
+
+        // route a: (2,1), (1,1), (2,1), (3,1), (3,2)
+        // route b: (2,1), (1,3), (2,3), (3,3), (3,2)
+        // first & last of the routes are the same
+        assert_eq!(result, 8);
+    }
+
+    #[test]
+    fn test_part_2_example() {
+        let input = read_to_string("./input_example.txt").unwrap();
+        let result = part_2(&input).unwrap();
+
+        assert_eq!(result, 45);
+    }
+
+    #[test]
+    fn test_part_2_example_2() {
+        let input = read_to_string("./input_example_2.txt").unwrap();
+        let result = part_2(&input).unwrap();
+
+        assert_eq!(result, 64);
+    }
+}
@@ -0,0 +1,42 @@
+//! Brute-forcing candidate values of a with the interpreter against the
+//! compiled program, e.g. `cargo bench -p day17`.
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use day17::{CompiledProgram, Computer, par_find_quine};
+use std::fs::read_to_string;
+use std::hint::black_box;
+use std::ops::Range;
+
+const CANDIDATES: Range<usize> = 1 << 40..(1 << 40) + 4096;
+
+fn bench_search(c: &mut Criterion) {
+    let input = read_to_string("./input.txt").unwrap();
+    let prototype: Computer = input.parse().unwrap();
+    let compiled = CompiledProgram::compile(&prototype);
+
+    let mut group = c.benchmark_group("day17_search_4096");
+    group.bench_function("computer_run", |b| {
+        b.iter(|| {
+            CANDIDATES.clone().find(|&a| {
+                let mut computer = prototype.clone();
+                computer.initialize_a(black_box(a));
+                computer.run().unwrap();
+                computer.has_output_itself()
+            })
+        })
+    });
+    group.bench_function("compiled", |b| {
+        b.iter(|| {
+            CANDIDATES
+                .clone()
+                .find(|&a| compiled.outputs_itself(black_box(a)))
+        })
+    });
+    group.bench_function("compiled_parallel", |b| {
+        b.iter(|| par_find_quine(&compiled, black_box(CANDIDATES)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_search);
+criterion_main!(benches);
@@ -0,0 +1,317 @@
+use std::collections::HashMap;
+
+use thiserror::Error;
+
+use crate::computer::{Opcode, OperandKind};
+
+/// comments run from any of these to the end of the line
+const COMMENT_MARKERS: [&str; 4] = [";", "#", "//", "--"];
+
+#[derive(Debug, Error, PartialEq)]
+pub enum AssemblerError {
+    #[error("line {0}: unknown instruction {1}")]
+    UnknownInstruction(usize, String),
+
+    #[error("line {0}: {1} expects an operand")]
+    MissingOperand(usize, &'static str),
+
+    #[error("line {0}: unexpected {1:?} after the operand")]
+    TrailingInput(usize, String),
+
+    #[error("line {0}: combo operand 7 is reserved and would fail at runtime")]
+    ReservedCombo(usize),
+
+    #[error("line {0}: {1:?} is not a valid {2} operand")]
+    InvalidOperand(usize, String, &'static str),
+
+    #[error("line {0}: invalid label {1:?}")]
+    InvalidLabel(usize, String),
+
+    #[error("line {0}: label {1} is already defined on line {2}")]
+    DuplicateLabel(usize, String, usize),
+
+    #[error("line {0}: unknown label {1}")]
+    UnknownLabel(usize, String),
+
+    #[error("line {0}: address {1} does not match the instruction's address {2}")]
+    AddressMismatch(usize, usize, usize),
+
+    #[error("line {0}: invalid directive {1:?}, expected .a, .b or .c and a value")]
+    InvalidDirective(usize, String),
+}
+
+use AssemblerError::*;
+
+/// an instruction whose operand has not been resolved yet, since it may refer
+/// to a label defined further down
+struct Statement<'a> {
+    line: usize,
+    opcode: Opcode,
+    operand: Option<&'a str>,
+}
+
+/// assembles a source listing into puzzle input that `Computer::from_str`
+/// accepts. Every non-empty line holds any number of `label:` prefixes
+/// followed by an instruction like `bst a` or `jnz loop`, or a `.a 729`
+/// directive setting a register's initial value. A numeric label such as
+/// `14:` asserts the address of the instruction that follows, so listings
+/// from the disassembler assemble back into the same program.
+pub fn assemble(source: &str) -> Result<String, AssemblerError> {
+    let mut registers = [0, 0, 0];
+    let mut statements = vec![];
+    let mut labels: HashMap<&str, (usize, usize)> = HashMap::new();
+
+    for (index, line) in source.lines().enumerate() {
+        let line_number = index + 1;
+        let mut rest = strip_comment(line).trim();
+        let address = statements.len() * 2;
+
+        while let Some((label, after)) = split_label(rest) {
+            match label.parse::<usize>() {
+                Ok(expected) if expected != address => {
+                    return Err(AddressMismatch(line_number, expected, address));
+                }
+                Ok(_) => {}
+                Err(_) if !is_identifier(label) => {
+                    return Err(InvalidLabel(line_number, label.to_string()));
+                }
+                Err(_) => {
+                    if let Some((_, defined_on)) = labels.insert(label, (address, line_number)) {
+                        return Err(DuplicateLabel(line_number, label.to_string(), defined_on));
+                    }
+                }
+            }
+            rest = after.trim();
+        }
+
+        if rest.is_empty() {
+            continue;
+        }
+
+        if let Some(directive) = rest.strip_prefix('.') {
+            let (register, value) = parse_directive(directive)
+                .ok_or_else(|| InvalidDirective(line_number, rest.to_string()))?;
+            registers[register] = value;
+            continue;
+        }
+
+        let mut words = rest.split_whitespace();
+        let mnemonic = words.next().unwrap_or_default();
+        let opcode = Opcode::ALL
+            .into_iter()
+            .find(|opcode| opcode.mnemonic().eq_ignore_ascii_case(mnemonic))
+            .ok_or_else(|| UnknownInstruction(line_number, mnemonic.to_string()))?;
+        let operand = words.next();
+
+        if let Some(extra) = words.next() {
+            return Err(TrailingInput(line_number, extra.to_string()));
+        }
+
+        statements.push(Statement {
+            line: line_number,
+            opcode,
+            operand,
+        });
+    }
+
+    let program: Vec<usize> = statements
+        .iter()
+        .map(|statement| {
+            let operand = resolve_operand(statement, &labels)?;
+            Ok([usize::from(statement.opcode), operand])
+        })
+        .collect::<Result<Vec<_>, _>>()?
+        .concat();
+
+    let program = program
+        .iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+
+    Ok(format!(
+        "Register A: {}\nRegister B: {}\nRegister C: {}\n\nProgram: {program}\n",
+        registers[0], registers[1], registers[2]
+    ))
+}
+
+fn strip_comment(line: &str) -> &str {
+    let end = COMMENT_MARKERS
+        .iter()
+        .filter_map(|marker| line.find(marker))
+        .min()
+        .unwrap_or(line.len());
+    &line[..end]
+}
+
+/// splits `loop: out b` into `loop` and ` out b`
+fn split_label(s: &str) -> Option<(&str, &str)> {
+    let (label, rest) = s.split_once(':')?;
+    match label.is_empty() || label.contains(char::is_whitespace) {
+        true => None,
+        false => Some((label, rest)),
+    }
+}
+
+fn is_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// `a 729` -> (0, 729)
+fn parse_directive(directive: &str) -> Option<(usize, usize)> {
+    let (register, value) = directive.split_once(char::is_whitespace)?;
+    let register = match register {
+        "a" | "A" => 0,
+        "b" | "B" => 1,
+        "c" | "C" => 2,
+        _ => return None,
+    };
+    Some((register, parse_number(value.trim())?))
+}
+
+/// decimal, or binary with a 0b prefix like the notes in program.js
+fn parse_number(s: &str) -> Option<usize> {
+    match s.strip_prefix("0b") {
+        Some(binary) => usize::from_str_radix(binary, 2).ok(),
+        None => s.parse().ok(),
+    }
+}
+
+fn resolve_operand(
+    statement: &Statement,
+    labels: &HashMap<&str, (usize, usize)>,
+) -> Result<usize, AssemblerError> {
+    let line = statement.line;
+    let kind = statement.opcode.operand_kind();
+
+    let Some(operand) = statement.operand else {
+        return match kind {
+            OperandKind::Ignored => Ok(0),
+            _ => Err(MissingOperand(line, statement.opcode.mnemonic())),
+        };
+    };
+
+    match kind {
+        OperandKind::Combo => match operand.to_ascii_lowercase().as_str() {
+            "a" => Ok(4),
+            "b" => Ok(5),
+            "c" => Ok(6),
+            _ => match parse_number(operand) {
+                Some(7) => Err(ReservedCombo(line)),
+                Some(combo @ 0..=6) => Ok(combo),
+                _ => Err(InvalidOperand(line, operand.to_string(), "combo")),
+            },
+        },
+        OperandKind::Literal | OperandKind::Ignored => match parse_number(operand) {
+            Some(literal @ 0..=7) => Ok(literal),
+            Some(_) => Err(InvalidOperand(line, operand.to_string(), "3-bit literal")),
+            None if statement.opcode == Opcode::Jnz => match labels.get(operand) {
+                Some(&(address, _)) if address <= 7 => Ok(address),
+                Some(_) => Err(InvalidOperand(line, operand.to_string(), "3-bit literal")),
+                None => Err(UnknownLabel(line, operand.to_string())),
+            },
+            None => Err(InvalidOperand(line, operand.to_string(), "3-bit literal")),
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::computer::Computer;
+    use crate::disassembler::disassemble;
+    use std::fs::read_to_string;
+
+    fn program(source: &str) -> Vec<usize> {
+        let input = assemble(source).unwrap();
+        input.parse::<Computer>().unwrap().program
+    }
+
+    #[test]
+    fn test_assemble_example() {
+        let source = "
+            .a 729
+            loop:
+                adv 1   ; a = a >> 1
+                out a
+                jnz loop
+        ";
+
+        assert_eq!(
+            assemble(source).unwrap(),
+            read_to_string("./input_example.txt").unwrap() + "\n"
+        );
+    }
+
+    #[test]
+    fn test_assemble_program_txt_style() {
+        let source = "
+            bst 4; -- 2, 4
+            bxl 1; -- 1, 1
+            cdv 5; -- 7, 5
+            bxc 4; -- 4, 4
+            bxl 0b100 # binary literal
+            adv 3
+            out b // register name
+            jnz 0
+        ";
+
+        assert_eq!(
+            program(source),
+            vec![2, 4, 1, 1, 7, 5, 4, 4, 1, 4, 0, 3, 5, 5, 3, 0]
+        );
+    }
+
+    #[test]
+    fn test_disassembly_round_trip() {
+        let input = read_to_string("./input.txt").unwrap();
+        let original: Computer = input.parse().unwrap();
+
+        let source = disassemble(&input).unwrap();
+
+        assert_eq!(program(&source), original.program);
+    }
+
+    #[test]
+    fn test_forward_label_and_bxc_without_operand() {
+        let source = "
+            jnz end
+            out 1
+            end: bxc
+        ";
+
+        assert_eq!(program(source), vec![3, 4, 5, 1, 4, 0]);
+    }
+
+    #[test]
+    fn test_errors_carry_line_numbers() {
+        assert_eq!(assemble("adv 1\nout 7"), Err(ReservedCombo(2)));
+        assert_eq!(
+            assemble("\n\nmul 3"),
+            Err(UnknownInstruction(3, "mul".to_string()))
+        );
+        assert_eq!(assemble("adv"), Err(MissingOperand(1, "adv")));
+        assert_eq!(
+            assemble("bxl 8"),
+            Err(InvalidOperand(1, "8".to_string(), "3-bit literal"))
+        );
+        assert_eq!(
+            assemble("jnz nowhere"),
+            Err(UnknownLabel(1, "nowhere".to_string()))
+        );
+        assert_eq!(
+            assemble("x: adv 1\nx: out a"),
+            Err(DuplicateLabel(2, "x".to_string(), 1))
+        );
+        assert_eq!(assemble("adv 1\n4: out a"), Err(AddressMismatch(2, 4, 2)));
+        assert_eq!(assemble("out a b"), Err(TrailingInput(1, "b".to_string())));
+        assert_eq!(
+            assemble(".d 3"),
+            Err(InvalidDirective(1, ".d 3".to_string()))
+        );
+    }
+}
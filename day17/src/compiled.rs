@@ -0,0 +1,292 @@
+use std::ops::Range;
+
+use rayon::prelude::*;
+
+use crate::computer::{Computer, Opcode, RuntimeError, shift};
+
+/// the most values a compiled run can output
+pub const OUTPUT_CAPACITY: usize = 32;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Operand {
+    Literal(usize),
+    A,
+    B,
+    C,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Instruction {
+    Adv(Operand),
+    Bxl(usize),
+    Bst(Operand),
+    Jnz(usize),
+    Bxc,
+    Out(Operand),
+    Bdv(Operand),
+    Cdv(Operand),
+    /// only fails once it is executed, like the interpreter
+    Unrecognized(usize),
+    /// a combo instruction with the reserved operand 7
+    Reserved(usize),
+    /// an opcode in the last cell, with no operand after it
+    Halt,
+}
+
+/// a program decoded once up front, so running it for another value of a is
+/// a table lookup per instruction and doesn't allocate
+#[derive(Clone, Debug)]
+pub struct CompiledProgram {
+    /// the instruction starting at every address, since `jnz` can jump to
+    /// odd ones too
+    instructions: Vec<Instruction>,
+    program: Vec<usize>,
+    b: usize,
+    c: usize,
+    max_steps: usize,
+}
+
+/// what a compiled run printed, without allocating
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Output {
+    values: [usize; OUTPUT_CAPACITY],
+    len: usize,
+}
+
+impl Output {
+    pub fn as_slice(&self) -> &[usize] {
+        &self.values[..self.len]
+    }
+}
+
+impl CompiledProgram {
+    /// decodes the computer's program, keeping its b and c registers and its
+    /// step budget for every run
+    pub fn compile(computer: &Computer) -> CompiledProgram {
+        let program = &computer.program;
+        let instructions = (0..program.len())
+            .map(|pointer| decode(program[pointer], program.get(pointer + 1).copied()))
+            .collect();
+
+        CompiledProgram {
+            instructions,
+            program: program.clone(),
+            b: computer.b,
+            c: computer.c,
+            max_steps: computer.limits.max_steps.unwrap_or(usize::MAX),
+        }
+    }
+
+    /// runs the program from a until it halts
+    pub fn run(&self, a: usize) -> Result<Output, RuntimeError> {
+        let mut output = Output {
+            values: [0; OUTPUT_CAPACITY],
+            len: 0,
+        };
+
+        self.execute(a, |value| match output.len < OUTPUT_CAPACITY {
+            true => {
+                output.values[output.len] = value;
+                output.len += 1;
+                Ok(true)
+            }
+            false => Err(RuntimeError::OutputOverflow(OUTPUT_CAPACITY)),
+        })?;
+
+        Ok(output)
+    }
+
+    /// whether running from a outputs exactly the program. Stops at the first
+    /// value that doesn't match, and counts any runtime error as a no.
+    pub fn outputs_itself(&self, a: usize) -> bool {
+        let mut matched = 0;
+
+        let halted = self.execute(a, |value| {
+            let matches = self.program.get(matched) == Some(&value);
+            matched += 1;
+            Ok(matches)
+        });
+
+        matches!(halted, Ok(true)) && matched == self.program.len()
+    }
+
+    /// runs from a, handing every output to `emit`, which returns false to
+    /// stop early. Returns whether the program halted by itself.
+    fn execute(
+        &self,
+        mut a: usize,
+        mut emit: impl FnMut(usize) -> Result<bool, RuntimeError>,
+    ) -> Result<bool, RuntimeError> {
+        let (mut b, mut c) = (self.b, self.c);
+        let mut pointer = 0;
+
+        for _ in 0..self.max_steps {
+            let Some(&instruction) = self.instructions.get(pointer) else {
+                return Ok(true);
+            };
+            let combo = |operand| resolve(operand, a, b, c);
+
+            match instruction {
+                Instruction::Adv(operand) => a = shift(a, combo(operand)),
+                Instruction::Bxl(literal) => b ^= literal,
+                Instruction::Bst(operand) => b = combo(operand) % 8,
+                Instruction::Jnz(target) if a != 0 => {
+                    pointer = target;
+                    continue;
+                }
+                Instruction::Jnz(_) => {}
+                Instruction::Bxc => b ^= c,
+                Instruction::Out(operand) => {
+                    if !emit(combo(operand) % 8)? {
+                        return Ok(false);
+                    }
+                }
+                Instruction::Bdv(operand) => b = shift(a, combo(operand)),
+                Instruction::Cdv(operand) => c = shift(a, combo(operand)),
+                Instruction::Unrecognized(opcode) => {
+                    return Err(RuntimeError::UnrecognizedInstruction(opcode));
+                }
+                Instruction::Reserved(operand) => {
+                    return Err(RuntimeError::UnrecognizedComboLiteral(operand, operand % 8));
+                }
+                Instruction::Halt => return Ok(true),
+            }
+            pointer += 2;
+        }
+
+        Err(RuntimeError::StepBudgetExhausted(self.max_steps))
+    }
+}
+
+fn resolve(operand: Operand, a: usize, b: usize, c: usize) -> usize {
+    match operand {
+        Operand::Literal(literal) => literal,
+        Operand::A => a,
+        Operand::B => b,
+        Operand::C => c,
+    }
+}
+
+fn decode(opcode: usize, operand: Option<usize>) -> Instruction {
+    let Some(operand) = operand else {
+        return Instruction::Halt;
+    };
+    let Ok(opcode) = Opcode::try_from(opcode) else {
+        return Instruction::Unrecognized(opcode);
+    };
+
+    let combo = match operand % 8 {
+        literal @ 0..=3 => Ok(Operand::Literal(literal)),
+        4 => Ok(Operand::A),
+        5 => Ok(Operand::B),
+        6 => Ok(Operand::C),
+        _ => Err(Instruction::Reserved(operand)),
+    };
+
+    let decoded = match opcode {
+        Opcode::Adv => combo.map(Instruction::Adv),
+        Opcode::Bxl => Ok(Instruction::Bxl(operand)),
+        Opcode::Bst => combo.map(Instruction::Bst),
+        Opcode::Jnz => Ok(Instruction::Jnz(operand)),
+        Opcode::Bxc => Ok(Instruction::Bxc),
+        Opcode::Out => combo.map(Instruction::Out),
+        Opcode::Bdv => combo.map(Instruction::Bdv),
+        Opcode::Cdv => combo.map(Instruction::Cdv),
+    };
+
+    decoded.unwrap_or_else(|reserved| reserved)
+}
+
+/// the lowest a in `candidates` that makes the program output itself, trying
+/// them on every core
+pub fn par_find_quine(compiled: &CompiledProgram, candidates: Range<usize>) -> Option<usize> {
+    candidates
+        .into_par_iter()
+        .find_first(|&a| compiled.outputs_itself(a))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs::read_to_string;
+
+    fn computer(path: &str) -> Computer {
+        read_to_string(path).unwrap().parse().unwrap()
+    }
+
+    #[test]
+    fn test_run_matches_interpreter() {
+        for path in ["./input_example.txt", "./input.txt"] {
+            let prototype = computer(path);
+            let compiled = CompiledProgram::compile(&prototype);
+
+            for a in [0, 1, 7, 729, 2024, 117440, prototype.a] {
+                let mut interpreted = prototype.clone();
+                interpreted.initialize_a(a);
+                interpreted.run().unwrap();
+
+                assert_eq!(compiled.run(a).unwrap().as_slice(), interpreted.output);
+            }
+        }
+    }
+
+    #[test]
+    fn test_shifting_everything_out_matches_interpreter() {
+        let mut prototype = Computer::new();
+        prototype.b = 64;
+        prototype.c = 200;
+        // cdv by b, out c, bdv by a, out b, adv by a, out a
+        prototype.program = vec![7, 5, 5, 6, 6, 4, 5, 5, 0, 4, 5, 4];
+
+        for a in [64, 12345, usize::MAX] {
+            let mut interpreted = prototype.clone();
+            interpreted.initialize_a(a);
+            interpreted.run().unwrap();
+
+            let compiled = CompiledProgram::compile(&prototype).run(a).unwrap();
+            assert_eq!(compiled.as_slice(), interpreted.output);
+            assert_eq!(interpreted.output, vec![0, 0, 0]);
+        }
+    }
+
+    #[test]
+    fn test_outputs_itself() {
+        let compiled = CompiledProgram::compile(&computer("./input_example_2.txt"));
+
+        assert!(compiled.outputs_itself(117440));
+        // the first adv drops the lowest 3 bits, so only higher bits matter
+        assert!(compiled.outputs_itself(117441));
+        assert!(!compiled.outputs_itself(117448));
+        assert!(!compiled.outputs_itself(0));
+    }
+
+    #[test]
+    fn test_runtime_errors() {
+        let mut prototype = Computer::new();
+        prototype.program = vec![5, 7];
+        assert!(matches!(
+            CompiledProgram::compile(&prototype).run(0),
+            Err(RuntimeError::UnrecognizedComboLiteral(7, 7))
+        ));
+
+        prototype.program = vec![5, 4, 3, 0];
+        prototype.limits.max_steps = Some(100);
+        assert!(matches!(
+            CompiledProgram::compile(&prototype).run(1),
+            Err(RuntimeError::OutputOverflow(OUTPUT_CAPACITY))
+        ));
+        prototype.program = vec![3, 0];
+        assert!(matches!(
+            CompiledProgram::compile(&prototype).run(1),
+            Err(RuntimeError::StepBudgetExhausted(100))
+        ));
+    }
+
+    #[test]
+    fn test_par_find_quine() {
+        let compiled = CompiledProgram::compile(&computer("./input_example_2.txt"));
+
+        assert_eq!(par_find_quine(&compiled, 0..200_000), Some(117440));
+        assert_eq!(par_find_quine(&compiled, 0..100_000), None);
+    }
+}
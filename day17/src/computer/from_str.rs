@@ -2,10 +2,10 @@ use itertools::Itertools;
 use std::{num::ParseIntError, str::FromStr};
 use thiserror::Error;
 
-use super::Computer;
+use super::{Computer, Limits};
 
 #[derive(Debug, Error)]
-pub(crate) enum ComputerParseError {
+pub enum ComputerParseError {
     #[error("No double newline found")]
     NoDoubleNewline,
 
@@ -50,6 +50,8 @@ impl FromStr for Computer {
             program,
             pointer: 0,
             output: vec![],
+            limits: Limits::default(),
+            trace: None,
         })
     }
 }
@@ -85,6 +87,8 @@ mod test {
                 program: vec![],
                 pointer: 0,
                 output: vec![],
+                limits: Limits::default(),
+                trace: None,
             }
         );
     }
@@ -108,6 +112,8 @@ mod test {
                 program: vec![],
                 pointer: 0,
                 output: vec![],
+                limits: Limits::default(),
+                trace: None,
             }
         );
     }
@@ -131,6 +137,8 @@ mod test {
                 program: vec![1, 2, 3, 4, 5],
                 pointer: 0,
                 output: vec![],
+                limits: Limits::default(),
+                trace: None,
             }
         );
     }
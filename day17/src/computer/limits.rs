@@ -0,0 +1,87 @@
+use super::{Computer, RuntimeError, StepOutcome};
+
+/// instructions `Computer::run` executes before giving up, unless configured
+/// otherwise
+pub const DEFAULT_MAX_STEPS: usize = 100_000_000;
+
+/// what stops a run of a program that never halts
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Limits {
+    /// fail once this many instructions have been executed
+    pub max_steps: Option<usize>,
+
+    /// fail once the machine is back in a state it has been in before. The
+    /// repeat is caught within two trips round the loop, not necessarily on
+    /// the first one.
+    pub detect_cycles: bool,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Limits {
+            max_steps: Some(DEFAULT_MAX_STEPS),
+            detect_cycles: true,
+        }
+    }
+}
+
+type State = (usize, usize, usize, usize);
+
+/// enforces `Limits` over a run, one instruction at a time
+pub(crate) struct Watchdog {
+    limits: Limits,
+    steps: usize,
+    /// Brent's cycle detection over the states right after each jump, which
+    /// only has to remember one of them however long the run is
+    saved: Option<State>,
+    power: usize,
+    since_saved: usize,
+}
+
+impl Watchdog {
+    pub(crate) fn new(limits: &Limits) -> Watchdog {
+        Watchdog {
+            limits: limits.clone(),
+            steps: 0,
+            saved: None,
+            power: 1,
+            since_saved: 1,
+        }
+    }
+
+    /// call after every executed instruction. Every loop has to jump, and the
+    /// state after a jump decides everything that follows, so a state after a
+    /// jump coming round again means the program never halts.
+    pub(crate) fn check(
+        &mut self,
+        computer: &Computer,
+        outcome: StepOutcome,
+    ) -> Result<(), RuntimeError> {
+        self.steps += 1;
+        if self.limits.max_steps.is_some_and(|max| self.steps > max) {
+            return Err(RuntimeError::StepBudgetExhausted(self.steps - 1));
+        }
+
+        if !self.limits.detect_cycles || !matches!(outcome, StepOutcome::Jumped(_)) {
+            return Ok(());
+        }
+
+        let state = (computer.pointer, computer.a, computer.b, computer.c);
+        if self.saved == Some(state) {
+            return Err(RuntimeError::CycleDetected(
+                state.0, state.1, state.2, state.3,
+            ));
+        }
+
+        // remember a state further along each time, so the gap to it
+        // eventually covers the whole loop
+        if self.since_saved == self.power {
+            self.saved = Some(state);
+            self.power *= 2;
+            self.since_saved = 0;
+        }
+        self.since_saved += 1;
+
+        Ok(())
+    }
+}
@@ -1,39 +1,75 @@
 mod from_str;
+mod limits;
+mod opcode;
+mod trace;
 
-use std::num::TryFromIntError;
 use thiserror::Error;
 
-pub(crate) use from_str::ComputerParseError;
+pub use from_str::ComputerParseError;
+pub(crate) use limits::Watchdog;
+pub use limits::{DEFAULT_MAX_STEPS, Limits};
+pub(crate) use opcode::{Opcode, OperandKind, combo_name};
+pub(crate) use trace::{Registers, Trace, TraceEntry};
+pub use trace::{TraceError, TraceFormat, trace};
 
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub(crate) struct Computer {
+pub struct Computer {
     pub(crate) a: usize,
     pub(crate) b: usize,
     pub(crate) c: usize,
     pub(crate) pointer: usize,
     pub(crate) program: Vec<usize>,
     pub(crate) output: Vec<usize>,
+    pub(crate) limits: Limits,
+    /// every executed instruction is recorded here while this is Some
+    pub(crate) trace: Option<Trace>,
 }
 
-#[derive(Debug, Error)]
-pub(crate) enum RuntimeError {
-    #[error("Ran out of instructions or combos")]
-    NoMoreInstructions,
+/// what executing a single instruction did
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StepOutcome {
+    /// the instruction ran and the pointer moved on to the next one
+    Advanced,
+
+    /// `jnz` moved the pointer to this address
+    Jumped(usize),
+
+    /// `out` emitted this value and the pointer moved on
+    Output(usize),
+
+    /// there was no instruction (or no operand) at the pointer, so nothing ran
+    Halted,
+}
+
+/// a >> by, where shifting every bit out leaves 0 rather than overflowing.
+/// Both the interpreter and the compiled program shift this way.
+pub(crate) fn shift(a: usize, by: usize) -> usize {
+    u32::try_from(by)
+        .ok()
+        .and_then(|by| a.checked_shr(by))
+        .unwrap_or(0)
+}
 
+#[derive(Debug, Error)]
+pub enum RuntimeError {
     #[error("Unrecognized instruction: {0}")]
     UnrecognizedInstruction(usize),
 
-    #[error("Failed to convert usize to u32")]
-    TryFromIntError(#[from] TryFromIntError),
-
     #[error("Unrecognized output combo: {0} % 8 = {1}")]
     UnrecognizedComboLiteral(usize, usize),
 
-    #[error("We jumped, so don't advance the pointer")]
-    JumpNoAdvancePointer,
+    #[error("Still running after {0} steps")]
+    StepBudgetExhausted(usize),
+
+    #[error("Stuck in a loop: pointer {0}, a {1}, b {2}, c {3} repeated")]
+    CycleDetected(usize, usize, usize, usize),
+
+    #[error("Output more than {0} values")]
+    OutputOverflow(usize),
 }
 
 use RuntimeError::*;
+use StepOutcome::*;
 
 impl Computer {
     #[allow(dead_code)]
@@ -45,10 +81,12 @@ impl Computer {
             program: vec![],
             pointer: 0,
             output: vec![],
+            limits: Limits::default(),
+            trace: None,
         }
     }
 
-    pub(crate) fn output_as_string(&self) -> String {
+    pub fn output_as_string(&self) -> String {
         self.output
             .iter()
             .map(|v| format!("{}", v))
@@ -66,68 +104,106 @@ impl Computer {
     }
 
     /// initialize register :a to value
-    #[allow(unused)]
-    pub(crate) fn initialize_a(&mut self, value: usize) {
+    pub fn initialize_a(&mut self, value: usize) {
         self.a = value
     }
 
+    /// sets what stops a run that never halts
+    pub fn set_limits(&mut self, limits: Limits) {
+        self.limits = limits
+    }
+
     /// checks to see if the current output equals the program
-    #[allow(unused)]
-    pub(crate) fn has_output_itself(&self) -> bool {
+    pub fn has_output_itself(&self) -> bool {
         self.output == self.program
     }
 
-    pub(crate) fn run(&mut self) -> Result<(), RuntimeError> {
-        loop {
-            match self.step() {
-                Ok(_) => self.pointer += 2,
-                Err(JumpNoAdvancePointer) => {}
-                Err(NoMoreInstructions) => return Ok(()), // execution is done
-                Err(e) => return Err(e),
-            }
-        }
+    /// runs until the program halts, or fails once it breaks `limits`
+    pub fn run(&mut self) -> Result<(), RuntimeError> {
+        self.run_until(|_| false)
     }
 
-    /// runs as long as the output is a subset of the program instructions. Will
-    /// early return as soon as the program outputs a value that doesn't match
-    /// the program.
-    #[allow(unused)]
-    pub(crate) fn run_for_matching_output(&mut self) -> Result<(), RuntimeError> {
+    /// runs until the program halts or `stop` returns true after an
+    /// instruction, whichever comes first
+    pub(crate) fn run_until(
+        &mut self,
+        mut stop: impl FnMut(&Computer) -> bool,
+    ) -> Result<(), RuntimeError> {
+        let mut watchdog = Watchdog::new(&self.limits);
+
         loop {
-            match self.step() {
-                Ok(_) => self.pointer += 2,
-                Err(JumpNoAdvancePointer) => {}
-                Err(NoMoreInstructions) => return Ok(()), // execution is done
-                Err(e) => return Err(e),
+            let outcome = self.step()?;
+            if outcome == Halted {
+                return Ok(());
             }
 
-            if !self.output_is_matching_so_far() {
-                return Ok(()); // abort
+            watchdog.check(self, outcome)?;
+            if stop(self) {
+                return Ok(());
             }
         }
     }
 
-    fn output_is_matching_so_far(&self) -> bool {
-        self.program.ends_with(&self.output)
-    }
-
-    fn step(&mut self) -> Result<(), RuntimeError> {
+    /// executes the instruction at the pointer and moves the pointer on, or
+    /// to wherever `jnz` jumped. Once the program has halted this keeps
+    /// returning `Halted` without changing anything.
+    pub fn step(&mut self) -> Result<StepOutcome, RuntimeError> {
         let p_i = self.pointer;
         let p_c = self.pointer + 1;
 
-        let instruction = *self.program.get(p_i).ok_or(NoMoreInstructions)?;
-        let combo = *self.program.get(p_c).ok_or(NoMoreInstructions)?;
+        let (Some(&instruction), Some(&combo)) = (self.program.get(p_i), self.program.get(p_c))
+        else {
+            return Ok(Halted);
+        };
 
-        match instruction {
-            0 => self.adv(combo),
-            1 => self.bxl(combo),
-            2 => self.bst(combo),
+        let before = (self.a, self.b, self.c);
+        let outcome = match instruction {
+            0 => self.adv(combo).map(|_| Advanced)?,
+            1 => self.bxl(combo).map(|_| Advanced)?,
+            2 => self.bst(combo).map(|_| Advanced)?,
             3 => self.jnz(combo),
-            4 => self.bxc(),
-            5 => self.out(combo),
-            6 => self.bdv(combo),
-            7 => self.cdv(combo),
-            _ => Err(UnrecognizedInstruction(instruction)),
+            4 => self.bxc().map(|_| Advanced)?,
+            5 => self.out(combo).map(Output)?,
+            6 => self.bdv(combo).map(|_| Advanced)?,
+            7 => self.cdv(combo).map(|_| Advanced)?,
+            _ => return Err(UnrecognizedInstruction(instruction)),
+        };
+
+        if self.trace.is_some() {
+            self.record(p_i, instruction, combo, before);
+        }
+
+        if !matches!(outcome, Jumped(_)) {
+            self.pointer += 2;
+        }
+        Ok(outcome)
+    }
+
+    /// appends the instruction that just ran to the trace
+    fn record(&mut self, pointer: usize, instruction: usize, operand: usize, before: Registers) {
+        let Ok(opcode) = Opcode::try_from(instruction) else {
+            return;
+        };
+        // registers the combo could have read are the ones from before
+        let combo = match (opcode.operand_kind(), operand % 8) {
+            (OperandKind::Combo, 4) => Some(before.0),
+            (OperandKind::Combo, 5) => Some(before.1),
+            (OperandKind::Combo, 6) => Some(before.2),
+            (OperandKind::Combo, literal) => Some(literal),
+            _ => None,
+        };
+
+        let entry = TraceEntry {
+            pointer,
+            opcode,
+            operand,
+            combo,
+            before,
+            after: (self.a, self.b, self.c),
+            output: self.output.clone(),
+        };
+        if let Some(trace) = self.trace.as_mut() {
+            trace.0.push(entry);
         }
     }
 
@@ -155,7 +231,7 @@ impl Computer {
     /// divide :a by 2^combo, store in :a
     fn adv(&mut self, combo_literal: usize) -> Result<(), RuntimeError> {
         let combo = self.combo(combo_literal)?;
-        self.a >>= combo;
+        self.a = shift(self.a, combo);
         Ok(())
     }
 
@@ -173,12 +249,12 @@ impl Computer {
     }
 
     /// update pointer to literal if :a is non-zero.
-    fn jnz(&mut self, literal: usize) -> Result<(), RuntimeError> {
+    fn jnz(&mut self, literal: usize) -> StepOutcome {
         match self.a {
-            0 => Ok(()),
+            0 => Advanced,
             _ => {
                 self.pointer = literal;
-                Err(JumpNoAdvancePointer)
+                Jumped(literal)
             }
         }
     }
@@ -190,46 +266,27 @@ impl Computer {
     }
 
     /// add combo % 8 to output
-    fn out(&mut self, combo_literal: usize) -> Result<(), RuntimeError> {
-        let combo = self.combo(combo_literal)?;
-        self.output.push(combo % 8);
-        Ok(())
+    fn out(&mut self, combo_literal: usize) -> Result<usize, RuntimeError> {
+        let value = self.combo(combo_literal)? % 8;
+        self.output.push(value);
+        Ok(value)
     }
 
     /// divide :a by 2^combo, store in :b
     fn bdv(&mut self, combo_literal: usize) -> Result<(), RuntimeError> {
         let combo = self.combo(combo_literal)?;
-        self.b = self.a >> combo;
+        self.b = shift(self.a, combo);
         Ok(())
     }
 
     /// divide :a by 2^combo, store in :c
     fn cdv(&mut self, combo_literal: usize) -> Result<(), RuntimeError> {
         let combo = self.combo(combo_literal)?;
-        self.c = self.a >> combo;
+        self.c = shift(self.a, combo);
         Ok(())
     }
 }
 
-pub(crate) fn find_target_input(prototype: &Computer, a: usize) -> Option<usize> {
-    let mut computer = prototype.clone();
-
-    computer.initialize_a(a);
-    computer.run().unwrap();
-
-    if computer.has_output_itself() {
-        return Some(a);
-    }
-    if !computer.output_is_matching_so_far() {
-        return None;
-    }
-
-    (0b000..=0b111).find_map(|suffix| match (a << 3) | suffix {
-        0 => None,
-        next_a => find_target_input(prototype, next_a),
-    })
-}
-
 #[cfg(test)]
 mod test {
     use std::fs::read_to_string;
@@ -468,6 +525,136 @@ mod test {
         assert_eq!(computer.b, 44354);
     }
 
+    #[test]
+    #[timeout(1000)]
+    fn test_step_outcomes() {
+        let mut computer = Computer::new();
+        computer.a = 1;
+        computer.program = vec![5, 4, 0, 1, 3, 0, 3, 8];
+
+        assert_eq!(computer.step().unwrap(), Output(1));
+        assert_eq!((computer.pointer, computer.output.clone()), (2, vec![1]));
+        assert_eq!(computer.step().unwrap(), Advanced);
+        assert_eq!(computer.pointer, 4);
+        assert_eq!(computer.step().unwrap(), Advanced); // a is 0, no jump
+        computer.a = 1;
+        assert_eq!(computer.step().unwrap(), Jumped(8));
+        assert_eq!(computer.pointer, 8);
+        assert_eq!(computer.step().unwrap(), Halted);
+        assert_eq!(computer.step().unwrap(), Halted);
+        assert_eq!(computer.pointer, 8);
+    }
+
+    #[test]
+    #[timeout(1000)]
+    fn test_jump_to_itself_does_not_advance() {
+        let mut computer = Computer::new();
+        computer.a = 1;
+        computer.program = vec![3, 0];
+
+        assert_eq!(computer.step().unwrap(), Jumped(0));
+        assert_eq!(computer.pointer, 0);
+    }
+
+    #[test]
+    #[timeout(1000)]
+    fn test_missing_operand_halts() {
+        let mut computer = Computer::new();
+        computer.program = vec![5, 4, 5];
+
+        computer.run().unwrap();
+
+        assert_eq!((computer.pointer, computer.output.clone()), (2, vec![0]));
+    }
+
+    #[test]
+    #[timeout(1000)]
+    fn test_trace_example() {
+        let input = read_to_string("./input_example.txt").unwrap();
+        let mut computer: Computer = input.parse().unwrap();
+        computer.trace = Some(Trace::default());
+
+        computer.run().unwrap();
+
+        let trace = computer.trace.unwrap().0;
+        assert_eq!(trace.len(), 30);
+        assert_eq!(
+            trace[1],
+            TraceEntry {
+                pointer: 2,
+                opcode: Opcode::Out,
+                operand: 4,
+                combo: Some(364),
+                before: (364, 0, 0),
+                after: (364, 0, 0),
+                output: vec![4],
+            }
+        );
+        assert_eq!(trace[29].output, computer.output);
+    }
+
+    #[test]
+    #[timeout(1000)]
+    fn test_no_trace_by_default() {
+        let input = read_to_string("./input_example.txt").unwrap();
+        let mut computer: Computer = input.parse().unwrap();
+
+        computer.run().unwrap();
+
+        assert_eq!(computer.trace, None);
+    }
+
+    #[test]
+    #[timeout(1000)]
+    fn test_step_budget() {
+        let mut computer = Computer::new();
+        computer.a = 1 << 40;
+        computer.program = vec![0, 1, 3, 0]; // halves a until it is 0
+        computer.limits.max_steps = Some(10);
+
+        assert!(matches!(computer.run(), Err(StepBudgetExhausted(10))));
+
+        computer.limits.max_steps = Some(100);
+        computer.pointer = 0;
+        computer.run().unwrap();
+        assert_eq!(computer.a, 0);
+    }
+
+    #[test]
+    #[timeout(1000)]
+    fn test_cycle_detected() {
+        let mut computer = Computer::new();
+        computer.a = 3;
+        computer.program = vec![2, 4, 5, 5, 3, 0]; // outputs a % 8 forever
+
+        assert!(matches!(computer.run(), Err(CycleDetected(0, 3, 3, 0))));
+        assert_eq!(computer.output, vec![3, 3]);
+    }
+
+    #[test]
+    #[timeout(1000)]
+    fn test_longer_cycle_detected() {
+        let mut computer = Computer::new();
+        computer.a = 1;
+        computer.program = vec![1, 1, 3, 0]; // flips b between 0 and 1 forever
+
+        assert!(matches!(computer.run(), Err(CycleDetected(0, 1, _, 0))));
+    }
+
+    #[test]
+    #[timeout(1000)]
+    fn test_cycle_detection_off_falls_back_to_budget() {
+        let mut computer = Computer::new();
+        computer.a = 3;
+        computer.program = vec![3, 0];
+        computer.limits = Limits {
+            max_steps: Some(1000),
+            detect_cycles: false,
+        };
+
+        assert!(matches!(computer.run(), Err(StepBudgetExhausted(1000))));
+    }
+
     #[test]
     #[timeout(1000)]
     fn test_example_equals_itself() {
@@ -0,0 +1,118 @@
+use std::fmt;
+
+/// the eight instructions of the 3-bit computer, in opcode order
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Opcode {
+    Adv,
+    Bxl,
+    Bst,
+    Jnz,
+    Bxc,
+    Out,
+    Bdv,
+    Cdv,
+}
+
+/// how an instruction interprets the operand that follows it
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum OperandKind {
+    Literal,
+    Combo,
+    Ignored,
+}
+
+use Opcode::*;
+
+impl Opcode {
+    pub(crate) const ALL: [Opcode; 8] = [Adv, Bxl, Bst, Jnz, Bxc, Out, Bdv, Cdv];
+
+    pub(crate) fn mnemonic(self) -> &'static str {
+        match self {
+            Adv => "adv",
+            Bxl => "bxl",
+            Bst => "bst",
+            Jnz => "jnz",
+            Bxc => "bxc",
+            Out => "out",
+            Bdv => "bdv",
+            Cdv => "cdv",
+        }
+    }
+
+    pub(crate) fn operand_kind(self) -> OperandKind {
+        match self {
+            Adv | Bst | Out | Bdv | Cdv => OperandKind::Combo,
+            Bxl | Jnz => OperandKind::Literal,
+            Bxc => OperandKind::Ignored,
+        }
+    }
+
+    /// what the instruction does, in terms of its (already rendered) operand
+    pub(crate) fn describe(self, operand: &str) -> String {
+        match self {
+            Adv => format!("a = a >> {operand}"),
+            Bxl => format!("b = b ^ {operand}"),
+            Bst => format!("b = {operand} % 8"),
+            Jnz => format!("if a != 0 jump to {operand}"),
+            Bxc => "b = b ^ c".to_string(),
+            Out => format!("output {operand} % 8"),
+            Bdv => format!("b = a >> {operand}"),
+            Cdv => format!("c = a >> {operand}"),
+        }
+    }
+}
+
+impl TryFrom<usize> for Opcode {
+    type Error = usize;
+
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        Opcode::ALL.get(value).copied().ok_or(value)
+    }
+}
+
+impl From<Opcode> for usize {
+    fn from(opcode: Opcode) -> Self {
+        opcode as usize
+    }
+}
+
+impl fmt::Display for Opcode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.mnemonic())
+    }
+}
+
+/// the name of a combo operand, taken % 8 like the computer does: 0-3 are
+/// literals, 4-6 are the registers and 7 is reserved
+pub(crate) fn combo_name(operand: usize) -> String {
+    match operand % 8 {
+        literal @ 0..=3 => literal.to_string(),
+        4 => "a".to_string(),
+        5 => "b".to_string(),
+        6 => "c".to_string(),
+        _ => format!("<reserved {operand}>"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_opcode_round_trip() {
+        for value in 0..8 {
+            let opcode = Opcode::try_from(value).unwrap();
+            assert_eq!(usize::from(opcode), value);
+        }
+        assert_eq!(Opcode::try_from(8), Err(8));
+    }
+
+    #[test]
+    fn test_combo_name() {
+        assert_eq!(combo_name(3), "3");
+        assert_eq!(combo_name(4), "a");
+        assert_eq!(combo_name(6), "c");
+        assert_eq!(combo_name(7), "<reserved 7>");
+        assert_eq!(combo_name(12), "a");
+    }
+}
@@ -0,0 +1,213 @@
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use super::{Computer, ComputerParseError, Opcode, RuntimeError};
+
+/// a, b and c
+pub(crate) type Registers = (usize, usize, usize);
+
+/// every instruction a computer executed while tracing was on
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(crate) struct Trace(pub(crate) Vec<TraceEntry>);
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct TraceEntry {
+    pub(crate) pointer: usize,
+    pub(crate) opcode: Opcode,
+    pub(crate) operand: usize,
+    /// the value a combo operand resolved to, for instructions that take one
+    pub(crate) combo: Option<usize>,
+    pub(crate) before: Registers,
+    pub(crate) after: Registers,
+    pub(crate) output: Vec<usize>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TraceFormat {
+    /// one JSON object per instruction
+    Jsonl,
+    /// a header row, then one row per instruction
+    Csv,
+}
+
+impl FromStr for TraceFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "jsonl" => Ok(TraceFormat::Jsonl),
+            "csv" => Ok(TraceFormat::Csv),
+            _ => Err(format!("unknown trace format {s}, expected jsonl or csv")),
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum TraceError {
+    #[error("Failed to parse computer")]
+    ComputerParseError(#[from] ComputerParseError),
+
+    #[error("Runtime Error: {0}")]
+    RuntimeError(#[from] RuntimeError),
+}
+
+/// runs the puzzle input's program, from `a` instead of register A if given,
+/// and exports every instruction it executed
+pub fn trace(input: &str, a: Option<usize>, format: TraceFormat) -> Result<String, TraceError> {
+    let mut computer: Computer = input.parse()?;
+    if let Some(a) = a {
+        computer.a = a;
+    }
+    computer.trace = Some(Trace::default());
+
+    computer.run()?;
+
+    Ok(computer.trace.unwrap_or_default().export(format))
+}
+
+const CSV_HEADER: &str = "step,pointer,opcode,mnemonic,operand,combo,a_before,b_before,c_before,a_after,b_after,c_after,output";
+
+impl Trace {
+    pub(crate) fn export(&self, format: TraceFormat) -> String {
+        match format {
+            TraceFormat::Jsonl => self.to_jsonl(),
+            TraceFormat::Csv => self.to_csv(),
+        }
+    }
+
+    pub(crate) fn to_jsonl(&self) -> String {
+        self.0
+            .iter()
+            .enumerate()
+            .map(|(step, entry)| {
+                let combo = entry
+                    .combo
+                    .map_or("null".to_string(), |combo| combo.to_string());
+                let registers = |(a, b, c): Registers| format!(r#"{{"a":{a},"b":{b},"c":{c}}}"#);
+
+                format!(
+                    r#"{{"step":{step},"pointer":{},"opcode":{},"mnemonic":"{}","operand":{},"combo":{combo},"before":{},"after":{},"output":[{}]}}"#,
+                    entry.pointer,
+                    usize::from(entry.opcode),
+                    entry.opcode,
+                    entry.operand,
+                    registers(entry.before),
+                    registers(entry.after),
+                    join(&entry.output),
+                )
+            })
+            .map(|line| line + "\n")
+            .collect()
+    }
+
+    pub(crate) fn to_csv(&self) -> String {
+        let rows = self.0.iter().enumerate().map(|(step, entry)| {
+            let combo = entry
+                .combo
+                .map_or("".to_string(), |combo| combo.to_string());
+            let (a, b, c) = entry.before;
+            let (a_after, b_after, c_after) = entry.after;
+
+            format!(
+                "{step},{},{},{},{},{combo},{a},{b},{c},{a_after},{b_after},{c_after},\"{}\"\n",
+                entry.pointer,
+                usize::from(entry.opcode),
+                entry.opcode,
+                entry.operand,
+                join(&entry.output),
+            )
+        });
+
+        std::iter::once(format!("{CSV_HEADER}\n"))
+            .chain(rows)
+            .collect()
+    }
+}
+
+fn join(values: &[usize]) -> String {
+    values
+        .iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn sample_trace() -> Trace {
+        Trace(vec![
+            TraceEntry {
+                pointer: 0,
+                opcode: Opcode::Adv,
+                operand: 1,
+                combo: Some(1),
+                before: (729, 0, 0),
+                after: (364, 0, 0),
+                output: vec![],
+            },
+            TraceEntry {
+                pointer: 2,
+                opcode: Opcode::Out,
+                operand: 4,
+                combo: Some(364),
+                before: (364, 0, 0),
+                after: (364, 0, 0),
+                output: vec![4],
+            },
+            TraceEntry {
+                pointer: 4,
+                opcode: Opcode::Jnz,
+                operand: 0,
+                combo: None,
+                before: (364, 0, 0),
+                after: (364, 0, 0),
+                output: vec![4],
+            },
+        ])
+    }
+
+    #[test]
+    fn test_to_jsonl() {
+        let result = sample_trace().to_jsonl();
+        let lines: Vec<_> = result.lines().collect();
+
+        assert_eq!(lines.len(), 3);
+        assert_eq!(
+            lines[1],
+            r#"{"step":1,"pointer":2,"opcode":5,"mnemonic":"out","operand":4,"combo":364,"before":{"a":364,"b":0,"c":0},"after":{"a":364,"b":0,"c":0},"output":[4]}"#
+        );
+        assert!(lines[2].contains(r#""combo":null"#));
+    }
+
+    #[test]
+    fn test_to_csv() {
+        let result = sample_trace().to_csv();
+        let lines: Vec<_> = result.lines().collect();
+
+        assert_eq!(lines[0], CSV_HEADER);
+        assert_eq!(lines[1], "0,0,0,adv,1,1,729,0,0,364,0,0,\"\"");
+        assert_eq!(lines[3], "2,4,3,jnz,0,,364,0,0,364,0,0,\"4\"");
+    }
+
+    #[test]
+    fn test_trace_different_a() {
+        let input = std::fs::read_to_string("./input_example_2.txt").unwrap();
+
+        let default = trace(&input, None, TraceFormat::Csv).unwrap();
+        let quine = trace(&input, Some(117440), TraceFormat::Csv).unwrap();
+
+        // a is 2024, so 4 loops of 3 instructions plus the header
+        assert_eq!(default.lines().count(), 13);
+        assert!(quine.ends_with(",\"0,3,5,4,3,0\"\n"));
+    }
+
+    #[test]
+    fn test_format_from_str() {
+        assert_eq!("csv".parse(), Ok(TraceFormat::Csv));
+        assert_eq!("jsonl".parse(), Ok(TraceFormat::Jsonl));
+        assert!("xml".parse::<TraceFormat>().is_err());
+    }
+}
@@ -0,0 +1,457 @@
+use std::collections::BTreeSet;
+use std::io::{self, BufRead, Write};
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use crate::computer::{Computer, ComputerParseError, Opcode, OperandKind, StepOutcome, Watchdog};
+use crate::disassembler::{listing_line, render};
+
+const HELP: &str = "\
+commands:
+  s, step [n]        execute the next n instructions (default 1), stopping early like continue
+  c, continue        run until a breakpoint, a watched register changes or the program halts
+  b, break <ptr>     stop before the instruction at ptr is executed
+  d, delete <ptr>    remove the breakpoint at ptr
+  w, watch <a|b|c>   stop and report whenever the register changes
+  unwatch <a|b|c>    stop watching the register
+  set <a|b|c> <n>    overwrite a register
+  r, regs            show the registers and the pointer
+  o, output          show the output so far
+  l, list            show the program, > marks the pointer and * the breakpoints
+  reset              restart the program with the registers from the input
+  h, help            show this message
+  q, quit            leave the debugger";
+
+#[derive(Debug, Error)]
+pub enum DebuggerError {
+    #[error("Failed to parse computer")]
+    ComputerParseError(#[from] ComputerParseError),
+
+    #[error("Failed to read or write the console")]
+    Io(#[from] io::Error),
+}
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+enum Register {
+    A,
+    B,
+    C,
+}
+
+impl Register {
+    fn get(self, computer: &Computer) -> usize {
+        match self {
+            Register::A => computer.a,
+            Register::B => computer.b,
+            Register::C => computer.c,
+        }
+    }
+
+    fn set(self, computer: &mut Computer, value: usize) {
+        match self {
+            Register::A => computer.a = value,
+            Register::B => computer.b = value,
+            Register::C => computer.c = value,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Register::A => "a",
+            Register::B => "b",
+            Register::C => "c",
+        }
+    }
+}
+
+impl FromStr for Register {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "a" | "A" => Ok(Register::A),
+            "b" | "B" => Ok(Register::B),
+            "c" | "C" => Ok(Register::C),
+            _ => Err(format!("unknown register {s}, expected a, b or c")),
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum Command {
+    Step(usize),
+    Continue,
+    Break(usize),
+    Delete(usize),
+    Watch(Register),
+    Unwatch(Register),
+    Set(Register, usize),
+    Registers,
+    Output,
+    List,
+    Reset,
+    Help,
+    Quit,
+}
+
+impl FromStr for Command {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let words: Vec<&str> = s.split_whitespace().collect();
+        let number = |word: &str| {
+            word.parse::<usize>()
+                .map_err(|_| format!("expected a number but got {word}"))
+        };
+
+        match words.as_slice() {
+            ["s" | "step"] => Ok(Command::Step(1)),
+            ["s" | "step", n] => Ok(Command::Step(number(n)?)),
+            ["c" | "continue"] => Ok(Command::Continue),
+            ["b" | "break", pointer] => Ok(Command::Break(number(pointer)?)),
+            ["d" | "delete", pointer] => Ok(Command::Delete(number(pointer)?)),
+            ["w" | "watch", register] => Ok(Command::Watch(register.parse()?)),
+            ["unwatch", register] => Ok(Command::Unwatch(register.parse()?)),
+            ["set", register, value] => Ok(Command::Set(register.parse()?, number(value)?)),
+            ["r" | "regs"] => Ok(Command::Registers),
+            ["o" | "output"] => Ok(Command::Output),
+            ["l" | "list"] => Ok(Command::List),
+            ["reset"] => Ok(Command::Reset),
+            ["h" | "help"] => Ok(Command::Help),
+            ["q" | "quit"] => Ok(Command::Quit),
+            _ => Err(format!("unknown command {s:?}, try help")),
+        }
+    }
+}
+
+/// why execution stopped before the requested number of steps
+enum Stop {
+    Breakpoint,
+    Watch,
+    Halted,
+}
+
+struct Debugger {
+    initial: Computer,
+    computer: Computer,
+    breakpoints: BTreeSet<usize>,
+    watches: BTreeSet<Register>,
+    halted: bool,
+}
+
+/// reads debugger commands line by line from `commands` until `quit` or the
+/// end of input, writing everything it shows to `out`
+pub fn debug(
+    input: &str,
+    commands: impl BufRead,
+    mut out: impl Write,
+) -> Result<(), DebuggerError> {
+    let computer: Computer = input.parse()?;
+    let mut debugger = Debugger {
+        initial: computer.clone(),
+        computer,
+        breakpoints: BTreeSet::new(),
+        watches: BTreeSet::new(),
+        halted: false,
+    };
+
+    debugger.show_next(&mut out)?;
+    let mut lines = commands.lines();
+    loop {
+        write!(out, "(day17) ")?;
+        out.flush()?;
+
+        let Some(line) = lines.next() else {
+            return Ok(());
+        };
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        match line.parse::<Command>() {
+            Ok(Command::Quit) => return Ok(()),
+            Ok(command) => debugger.execute(command, &mut out)?,
+            Err(message) => writeln!(out, "{message}")?,
+        }
+    }
+}
+
+impl Debugger {
+    fn execute(&mut self, command: Command, out: &mut impl Write) -> io::Result<()> {
+        match command {
+            Command::Step(n) => {
+                for _ in 0..n {
+                    match self.step(out, true)? {
+                        Ok(_) => continue,
+                        Err(Stop::Breakpoint) => writeln!(out, "breakpoint")?,
+                        Err(Stop::Watch) | Err(Stop::Halted) => {}
+                    }
+                    break;
+                }
+                self.show_next(out)?;
+            }
+            Command::Continue => {
+                let mut watchdog = Watchdog::new(&self.computer.limits);
+                loop {
+                    match self.step(out, false)? {
+                        Ok(outcome) => match watchdog.check(&self.computer, outcome) {
+                            Ok(()) => continue,
+                            Err(e) => writeln!(out, "stopped: {e}")?,
+                        },
+                        Err(Stop::Breakpoint) => writeln!(out, "breakpoint")?,
+                        Err(Stop::Watch) | Err(Stop::Halted) => {}
+                    }
+                    break;
+                }
+                self.show_next(out)?;
+            }
+            Command::Break(pointer) => {
+                self.breakpoints.insert(pointer);
+                writeln!(out, "breakpoint at {pointer}")?;
+            }
+            Command::Delete(pointer) => match self.breakpoints.remove(&pointer) {
+                true => writeln!(out, "deleted breakpoint at {pointer}")?,
+                false => writeln!(out, "no breakpoint at {pointer}")?,
+            },
+            Command::Watch(register) => {
+                self.watches.insert(register);
+                writeln!(out, "watching {}", register.name())?;
+            }
+            Command::Unwatch(register) => {
+                self.watches.remove(&register);
+                writeln!(out, "stopped watching {}", register.name())?;
+            }
+            Command::Set(register, value) => {
+                register.set(&mut self.computer, value);
+                self.show_registers(out)?;
+            }
+            Command::Registers => self.show_registers(out)?,
+            Command::Output => writeln!(out, "output: {}", self.computer.output_as_string())?,
+            Command::List => {
+                let program = &self.computer.program;
+                for pointer in (0..program.len()).step_by(2) {
+                    let marker = match (
+                        pointer == self.computer.pointer,
+                        self.breakpoints.contains(&pointer),
+                    ) {
+                        (true, true) => ">*",
+                        (true, false) => "> ",
+                        (false, true) => " *",
+                        (false, false) => "  ",
+                    };
+                    writeln!(out, "{marker}{}", listing_line(program, pointer))?;
+                }
+            }
+            Command::Reset => {
+                self.computer = self.initial.clone();
+                self.halted = false;
+                self.show_next(out)?;
+            }
+            Command::Help => writeln!(out, "{HELP}")?,
+            Command::Quit => {}
+        }
+
+        Ok(())
+    }
+
+    /// executes one instruction, reporting it when `verbose`. Watched register
+    /// changes and new output are always reported.
+    fn step(
+        &mut self,
+        out: &mut impl Write,
+        verbose: bool,
+    ) -> io::Result<Result<StepOutcome, Stop>> {
+        if self.halted {
+            writeln!(out, "the program has halted, reset to start again")?;
+            return Ok(Err(Stop::Halted));
+        }
+
+        let before = self.computer.clone();
+        if verbose {
+            writeln!(out, "{}", self.resolved_line())?;
+        }
+
+        let outcome = match self.computer.step() {
+            Ok(StepOutcome::Halted) => {
+                self.halted = true;
+                writeln!(out, "halted, output: {}", self.computer.output_as_string())?;
+                return Ok(Err(Stop::Halted));
+            }
+            Ok(outcome) => outcome,
+            Err(e) => {
+                self.halted = true;
+                writeln!(out, "{}\nstopped: {e}", self.resolved_line())?;
+                return Ok(Err(Stop::Halted));
+            }
+        };
+
+        if let StepOutcome::Output(_) = outcome {
+            writeln!(out, "  output: {}", self.computer.output_as_string())?;
+        }
+
+        let mut watched_change = false;
+        for register in self.watches.iter() {
+            let (old, new) = (register.get(&before), register.get(&self.computer));
+            if old != new {
+                writeln!(out, "  {}: {old} -> {new}", register.name())?;
+                watched_change = true;
+            }
+        }
+
+        if watched_change {
+            return Ok(Err(Stop::Watch));
+        }
+        match self.breakpoints.contains(&self.computer.pointer) {
+            true => Ok(Err(Stop::Breakpoint)),
+            false => Ok(Ok(outcome)),
+        }
+    }
+
+    /// the listing line of the next instruction, with the value of a register
+    /// combo operand filled in, e.g. `  0: bst a (= 729) ; b = a % 8`
+    fn resolved_line(&self) -> String {
+        let program = &self.computer.program;
+        let pointer = self.computer.pointer;
+
+        let is_combo = program
+            .get(pointer)
+            .and_then(|&raw| Opcode::try_from(raw).ok())
+            .is_some_and(|opcode| opcode.operand_kind() == OperandKind::Combo);
+        let resolved = match program.get(pointer + 1).map(|operand| operand % 8) {
+            Some(4) if is_combo => Some(self.computer.a),
+            Some(5) if is_combo => Some(self.computer.b),
+            Some(6) if is_combo => Some(self.computer.c),
+            _ => None,
+        };
+
+        match (resolved, render(program, pointer)) {
+            (Some(value), Some(rendered)) => format!(
+                "{pointer:>3}: {:<8} ; {}",
+                format!("{} (= {value})", rendered.text),
+                rendered.description
+            ),
+            _ => listing_line(program, pointer),
+        }
+    }
+
+    fn show_next(&self, out: &mut impl Write) -> io::Result<()> {
+        match self.halted {
+            true => Ok(()),
+            false => writeln!(out, "{}", self.resolved_line()),
+        }
+    }
+
+    fn show_registers(&self, out: &mut impl Write) -> io::Result<()> {
+        let computer = &self.computer;
+        writeln!(
+            out,
+            "a: {} b: {} c: {} pointer: {}",
+            computer.a, computer.b, computer.c, computer.pointer
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ntest::timeout;
+    use std::fs::read_to_string;
+
+    fn session(commands: &str) -> String {
+        let input = read_to_string("./input_example.txt").unwrap();
+        let mut out = vec![];
+
+        debug(&input, commands.as_bytes(), &mut out).unwrap();
+
+        String::from_utf8(out).unwrap().replace("(day17) ", "")
+    }
+
+    #[test]
+    fn test_parse_commands() {
+        assert_eq!("step".parse(), Ok(Command::Step(1)));
+        assert_eq!("s 5".parse(), Ok(Command::Step(5)));
+        assert_eq!("b 4".parse(), Ok(Command::Break(4)));
+        assert_eq!("watch a".parse(), Ok(Command::Watch(Register::A)));
+        assert_eq!("set c 7".parse(), Ok(Command::Set(Register::C, 7)));
+        assert!("watch d".parse::<Command>().is_err());
+        assert!("jump".parse::<Command>().is_err());
+    }
+
+    #[test]
+    #[timeout(1000)]
+    fn test_step_shows_resolved_operands() {
+        let result = session("step 2\nregs\n");
+
+        assert_eq!(
+            result,
+            [
+                "  0: adv 1    ; a = a >> 1",
+                "  0: adv 1    ; a = a >> 1",
+                "  2: out a (= 364) ; output a % 8",
+                "  output: 4",
+                "  4: jnz 0    ; if a != 0 jump to 0",
+                "a: 364 b: 0 c: 0 pointer: 4",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    #[timeout(1000)]
+    fn test_breakpoint_and_continue() {
+        let result = session("break 4\ncontinue\ncontinue\nregs\n");
+
+        assert!(result.contains("breakpoint at 4\n  output: 4\nbreakpoint\n"));
+        assert!(result.ends_with("a: 182 b: 0 c: 0 pointer: 4\n"));
+    }
+
+    #[test]
+    #[timeout(1000)]
+    fn test_step_stops_early() {
+        let at_breakpoint = session("break 4\nstep 5\nregs\n");
+        assert!(at_breakpoint.contains("  output: 4\nbreakpoint\n"));
+        assert!(at_breakpoint.ends_with("a: 364 b: 0 c: 0 pointer: 4\n"));
+
+        let on_watch = session("watch a\nstep 5\nregs\n");
+        assert!(on_watch.contains("  a: 729 -> 364\n"));
+        assert!(on_watch.ends_with("a: 364 b: 0 c: 0 pointer: 2\n"));
+    }
+
+    #[test]
+    #[timeout(1000)]
+    fn test_watch_stops_on_change() {
+        let result = session("watch a\ncontinue\n");
+
+        assert!(result.contains("  a: 729 -> 364\n"));
+    }
+
+    #[test]
+    #[timeout(1000)]
+    fn test_continue_stops_in_an_endless_loop() {
+        let mut out = vec![];
+
+        debug(
+            "Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 3,0",
+            "c\n".as_bytes(),
+            &mut out,
+        )
+        .unwrap();
+
+        let result = String::from_utf8(out).unwrap();
+        assert!(result.contains("stopped: Stuck in a loop: pointer 0, a 1, b 0, c 0 repeated\n"));
+    }
+
+    #[test]
+    #[timeout(1000)]
+    fn test_continue_until_halted() {
+        let result = session("continue\noutput\nstep\nreset\nregs\nquit\nregs\n");
+
+        assert!(result.contains("halted, output: 4,6,3,5,6,3,5,2,1,0\n"));
+        assert!(result.contains("output: 4,6,3,5,6,3,5,2,1,0\n"));
+        assert!(result.contains("the program has halted, reset to start again\n"));
+        assert!(result.ends_with("a: 729 b: 0 c: 0 pointer: 0\n"));
+    }
+}
@@ -0,0 +1,123 @@
+use crate::computer::{Computer, ComputerParseError, Opcode, OperandKind, combo_name};
+
+/// one decoded instruction, e.g. `bst a` and what it does, `b = a % 8`
+#[derive(Debug, Eq, PartialEq)]
+pub(crate) struct Rendered {
+    pub(crate) text: String,
+    pub(crate) description: String,
+}
+
+/// renders the puzzle input's program as a listing of mnemonics, one
+/// instruction per line
+pub fn disassemble(input: &str) -> Result<String, ComputerParseError> {
+    let computer: Computer = input.parse()?;
+    Ok(listing(&computer.program))
+}
+
+pub(crate) fn listing(program: &[usize]) -> String {
+    (0..program.len())
+        .step_by(2)
+        .map(|pointer| listing_line(program, pointer))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// `  2: bxl 1    ; b = b ^ 1`
+pub(crate) fn listing_line(program: &[usize], pointer: usize) -> String {
+    match render(program, pointer) {
+        Some(rendered) => format!(
+            "{pointer:>3}: {:<8} ; {}",
+            rendered.text, rendered.description
+        ),
+        None => format!("{pointer:>3}: <end of program>"),
+    }
+}
+
+/// decodes the instruction at `pointer`, which need not be even since `jnz`
+/// can jump anywhere. Returns None past the end of the program.
+pub(crate) fn render(program: &[usize], pointer: usize) -> Option<Rendered> {
+    let raw = *program.get(pointer)?;
+    let operand = program.get(pointer + 1).copied();
+
+    let Ok(opcode) = Opcode::try_from(raw) else {
+        return Some(Rendered {
+            text: format!("??? {raw}"),
+            description: "unrecognized instruction".to_string(),
+        });
+    };
+
+    let Some(operand) = operand else {
+        return Some(Rendered {
+            text: opcode.mnemonic().to_string(),
+            description: "missing operand, halts".to_string(),
+        });
+    };
+
+    let operand = match (opcode.operand_kind(), operand) {
+        (OperandKind::Literal, literal) => literal.to_string(),
+        (OperandKind::Combo, combo) => combo_name(combo),
+        (OperandKind::Ignored, 0) => "".to_string(),
+        (OperandKind::Ignored, ignored) => ignored.to_string(),
+    };
+
+    let text = match operand.is_empty() {
+        true => opcode.mnemonic().to_string(),
+        false => format!("{} {operand}", opcode.mnemonic()),
+    };
+
+    Some(Rendered {
+        text,
+        description: opcode.describe(&operand),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs::read_to_string;
+
+    #[test]
+    fn test_render_resolves_combo_operands() {
+        let program = vec![2, 4, 1, 1, 7, 5, 4, 4, 0, 3, 5, 6, 3, 0];
+
+        let texts: Vec<_> = (0..program.len())
+            .step_by(2)
+            .map(|pointer| render(&program, pointer).unwrap().text)
+            .collect();
+
+        assert_eq!(
+            texts,
+            vec![
+                "bst a", "bxl 1", "cdv b", "bxc 4", "adv 3", "out c", "jnz 0"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_edge_cases() {
+        assert_eq!(render(&[], 0), None);
+        assert_eq!(
+            render(&[5], 0).unwrap().description,
+            "missing operand, halts"
+        );
+        assert_eq!(render(&[9, 0], 0).unwrap().text, "??? 9");
+        assert_eq!(render(&[0, 7], 0).unwrap().text, "adv <reserved 7>");
+        // jumping to an odd pointer decodes the operand as an instruction
+        assert_eq!(render(&[3, 5, 4], 1).unwrap().text, "out a");
+    }
+
+    #[test]
+    fn test_disassemble_example() {
+        let input = read_to_string("./input_example.txt").unwrap();
+
+        assert_eq!(
+            disassemble(&input).unwrap(),
+            [
+                "  0: adv 1    ; a = a >> 1",
+                "  2: out a    ; output a % 8",
+                "  4: jnz 0    ; if a != 0 jump to 0",
+            ]
+            .join("\n")
+        );
+    }
+}
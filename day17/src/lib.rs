@@ -1,17 +1,35 @@
+mod assembler;
+mod compiled;
 mod computer;
-mod custom_program;
-mod custom_program_a;
+mod debugger;
+mod disassembler;
+mod quine;
 
-use std::fs::read_to_string;
 use thiserror::Error;
 
-use crate::computer::{Computer, ComputerParseError, RuntimeError, find_target_input};
+use crate::quine::{FALLBACK_LIMIT, QuineError, find_quine};
+use aoc_solution::Solution;
+use std::error::Error;
 
-fn main() {
-    let input = read_to_string("./input.txt").unwrap();
+pub use assembler::{AssemblerError, assemble};
+pub use compiled::{CompiledProgram, OUTPUT_CAPACITY, Output, par_find_quine};
+pub use computer::{
+    Computer, ComputerParseError, DEFAULT_MAX_STEPS, Limits, RuntimeError, StepOutcome, TraceError,
+    TraceFormat, trace,
+};
+pub use debugger::{DebuggerError, debug};
+pub use disassembler::disassemble;
 
-    println!("part_1: {}", part_1(&input).unwrap());
-    println!("part_2: {}", part_2(&input).unwrap());
+pub struct Day17;
+
+impl Solution for Day17 {
+    fn part_1(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        Ok(part_1(input)?.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        Ok(part_2(input)?.to_string())
+    }
 }
 
 #[derive(Debug, Error)]
@@ -23,8 +41,8 @@ enum ComputerError {
     #[error("Runtime Error")]
     RuntimeError(#[from] RuntimeError),
 
-    #[error("No Input Found for output")]
-    NoInputFound,
+    #[error("Failed to find a quine: {0}")]
+    QuineError(#[from] QuineError),
 }
 
 fn part_1(input: &str) -> Result<String, ComputerError> {
@@ -35,17 +53,13 @@ fn part_1(input: &str) -> Result<String, ComputerError> {
 
 fn part_2(input: &str) -> Result<usize, ComputerError> {
     let computer: Computer = input.parse()?;
-
-    let result = (0b000..=0b111)
-        .find_map(|input| find_target_input(&computer, input))
-        .ok_or(ComputerError::NoInputFound)?;
-
-    Ok(result)
+    Ok(find_quine(&computer, FALLBACK_LIMIT)?)
 }
 
 #[cfg(test)]
 mod test {
     use ntest::timeout;
+    use std::fs::read_to_string;
 
     use super::*;
 
@@ -0,0 +1,287 @@
+use thiserror::Error;
+
+use crate::compiled::{CompiledProgram, par_find_quine};
+use crate::computer::{Computer, Opcode, RuntimeError, StepOutcome};
+
+/// how many values of a the fallback search tries for programs the digit by
+/// digit search can't handle
+pub(crate) const FALLBACK_LIMIT: usize = 1 << 20;
+
+/// instructions executed per candidate before the fallback search gives up on
+/// it, since an arbitrary program may never halt
+const FALLBACK_MAX_STEPS: usize = 10_000;
+
+/// the shape that lets a be found a few bits at a time: a single loop ending in
+/// `jnz 0` that shifts a right by a constant once and outputs once per
+/// iteration, with b and c recomputed from a before they are read
+#[derive(Debug, Eq, PartialEq)]
+pub(crate) struct LoopShape {
+    pub(crate) shift: usize,
+}
+
+/// why a program doesn't have a `LoopShape`
+#[derive(Debug, Error, Eq, PartialEq)]
+pub(crate) enum Unsupported {
+    #[error("it has an odd number of values")]
+    OddLength,
+
+    #[error("it does not end in jnz 0")]
+    NoFinalJump,
+
+    #[error("it jumps at {0} before the final jnz")]
+    InnerJump(usize),
+
+    #[error("it has an unrecognized instruction at {0}")]
+    UnrecognizedInstruction(usize),
+
+    #[error("it uses the reserved combo operand at {0}")]
+    ReservedCombo(usize),
+
+    #[error("it outputs {0} values per iteration instead of one")]
+    OutputCount(usize),
+
+    #[error("it shifts a {0} times per iteration instead of once")]
+    ShiftCount(usize),
+
+    #[error("it shifts a by a register or 0 instead of a constant at {0}")]
+    VariableShift(usize),
+
+    #[error("it reads {1} at {0} before assigning it, so iterations depend on each other")]
+    CarriedRegister(usize, char),
+}
+
+#[derive(Debug, Error)]
+pub(crate) enum QuineError {
+    #[error("No value of a makes the program output itself")]
+    NoQuine,
+
+    #[error(
+        "The program can't be solved digit by digit because {0}, and no a below {1} makes it output itself"
+    )]
+    NotAmenable(Unsupported, usize),
+
+    #[error("Runtime Error")]
+    RuntimeError(#[from] RuntimeError),
+}
+
+use Unsupported::*;
+
+/// checks the program has the shape the digit by digit search relies on
+pub(crate) fn analyse(program: &[usize]) -> Result<LoopShape, Unsupported> {
+    if !program.len().is_multiple_of(2) {
+        return Err(OddLength);
+    }
+    let Some((body, [3, 0])) = program.split_last_chunk::<2>() else {
+        return Err(NoFinalJump);
+    };
+
+    let mut outputs = 0;
+    let mut shifts = vec![];
+    // a is the loop variable, b and c must be assigned before they are read
+    let mut assigned = vec!['a'];
+
+    for (index, pair) in body.chunks(2).enumerate() {
+        let pointer = index * 2;
+        let (opcode, operand) = (pair[0], pair[1]);
+        let opcode = Opcode::try_from(opcode).map_err(|_| UnrecognizedInstruction(pointer))?;
+
+        // the register a combo operand reads, if any
+        let combo = || match operand % 8 {
+            0..=3 => Ok(vec![]),
+            4 => Ok(vec!['a']),
+            5 => Ok(vec!['b']),
+            6 => Ok(vec!['c']),
+            _ => Err(ReservedCombo(pointer)),
+        };
+
+        let (reads, writes) = match opcode {
+            Opcode::Adv => (combo()?, Some('a')),
+            Opcode::Bxl => (vec!['b'], Some('b')),
+            Opcode::Bst => (combo()?, Some('b')),
+            Opcode::Jnz => return Err(InnerJump(pointer)),
+            Opcode::Bxc => (vec!['b', 'c'], Some('b')),
+            Opcode::Out => (combo()?, None),
+            Opcode::Bdv => ([combo()?, vec!['a']].concat(), Some('b')),
+            Opcode::Cdv => ([combo()?, vec!['a']].concat(), Some('c')),
+        };
+
+        if let Some(&register) = reads.iter().find(|register| !assigned.contains(register)) {
+            return Err(CarriedRegister(pointer, register));
+        }
+
+        match opcode {
+            Opcode::Adv if (1..=3).contains(&(operand % 8)) => shifts.push(operand % 8),
+            Opcode::Adv => return Err(VariableShift(pointer)),
+            Opcode::Out => outputs += 1,
+            _ => {}
+        }
+        if let Some(register) = writes {
+            assigned.push(register);
+        }
+    }
+
+    match (outputs, shifts.as_slice()) {
+        (1, [shift]) => Ok(LoopShape { shift: *shift }),
+        (1, shifts) => Err(ShiftCount(shifts.len())),
+        (outputs, _) => Err(OutputCount(outputs)),
+    }
+}
+
+/// finds the lowest initial a that makes the program output itself
+pub(crate) fn find_quine(prototype: &Computer, fallback_limit: usize) -> Result<usize, QuineError> {
+    match analyse(&prototype.program) {
+        Ok(shape) => {
+            let last = prototype.program.len() - 1;
+            search_digits(prototype, &shape, last, 0)?.ok_or(QuineError::NoQuine)
+        }
+        Err(reason) => search_brute_force(prototype, fallback_limit)
+            .ok_or(QuineError::NotAmenable(reason, fallback_limit)),
+    }
+}
+
+/// every iteration outputs one value and shifts `shift` bits off a, so the
+/// last output only depends on the top bits of a. Working backwards from the
+/// last output, each level tries the `shift` bits below the ones already
+/// found, lowest first, so the first full match is the lowest a.
+fn search_digits(
+    prototype: &Computer,
+    shape: &LoopShape,
+    index: usize,
+    above: usize,
+) -> Result<Option<usize>, RuntimeError> {
+    let last = prototype.program.len() - 1;
+
+    for suffix in 0..(1 << shape.shift) {
+        let a = (above << shape.shift) | suffix;
+        // a loop that runs again must see a non-zero a
+        if a == 0 && index == last && last > 0 {
+            continue;
+        }
+        if run_iteration(prototype, a)? != Some(prototype.program[index]) {
+            continue;
+        }
+
+        let found = match index {
+            0 => match is_quine(prototype, a)? {
+                true => Some(a),
+                false => None,
+            },
+            _ => search_digits(prototype, shape, index - 1, a)?,
+        };
+        if found.is_some() {
+            return Ok(found);
+        }
+    }
+
+    Ok(None)
+}
+
+/// runs the loop body once with the given a and returns what it outputs
+fn run_iteration(prototype: &Computer, a: usize) -> Result<Option<usize>, RuntimeError> {
+    let mut computer = prototype.clone();
+    computer.a = a;
+    let jump = computer.program.len() - 2;
+
+    while computer.pointer != jump && computer.step()? != StepOutcome::Halted {}
+
+    Ok(computer.output.first().copied())
+}
+
+/// tries every a below `limit`, counting a runtime error as not a quine since
+/// an arbitrary program may fault or loop forever for some values of a but
+/// not others
+fn search_brute_force(prototype: &Computer, limit: usize) -> Option<usize> {
+    let mut prototype = prototype.clone();
+    prototype.limits.max_steps = Some(FALLBACK_MAX_STEPS);
+
+    par_find_quine(&CompiledProgram::compile(&prototype), 0..limit)
+}
+
+/// runs the program from a until it halts or strays from its own program in
+/// the output
+fn is_quine(prototype: &Computer, a: usize) -> Result<bool, RuntimeError> {
+    let mut computer = prototype.clone();
+    computer.a = a;
+
+    computer.run_until(|computer| !computer.program.starts_with(&computer.output))?;
+
+    Ok(computer.output == computer.program)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs::read_to_string;
+
+    fn computer(path: &str) -> Computer {
+        read_to_string(path).unwrap().parse().unwrap()
+    }
+
+    #[test]
+    fn test_analyse_examples_and_input() {
+        assert_eq!(
+            analyse(&computer("./input_example.txt").program),
+            Ok(LoopShape { shift: 1 })
+        );
+        assert_eq!(
+            analyse(&computer("./input_example_2.txt").program),
+            Ok(LoopShape { shift: 3 })
+        );
+        assert_eq!(
+            analyse(&computer("./input.txt").program),
+            Ok(LoopShape { shift: 3 })
+        );
+    }
+
+    #[test]
+    fn test_analyse_unsupported() {
+        assert_eq!(analyse(&[0, 3, 5, 4, 3]), Err(OddLength));
+        assert_eq!(analyse(&[0, 3, 5, 4]), Err(NoFinalJump));
+        assert_eq!(analyse(&[0, 3, 5, 4, 3, 2]), Err(NoFinalJump));
+        assert_eq!(analyse(&[3, 4, 0, 3, 5, 4, 3, 0]), Err(InnerJump(0)));
+        assert_eq!(analyse(&[0, 3, 5, 7, 3, 0]), Err(ReservedCombo(2)));
+        assert_eq!(analyse(&[0, 3, 5, 4, 5, 4, 3, 0]), Err(OutputCount(2)));
+        assert_eq!(analyse(&[0, 3, 0, 1, 5, 4, 3, 0]), Err(ShiftCount(2)));
+        assert_eq!(analyse(&[0, 5, 5, 4, 3, 0]), Err(CarriedRegister(0, 'b')));
+        assert_eq!(
+            analyse(&[2, 4, 0, 6, 5, 4, 3, 0]),
+            Err(CarriedRegister(2, 'c'))
+        );
+        assert_eq!(analyse(&[6, 3, 0, 5, 5, 4, 3, 0]), Err(VariableShift(2)));
+    }
+
+    #[test]
+    fn test_find_quine_example_2() {
+        let result = find_quine(&computer("./input_example_2.txt"), 0).unwrap();
+
+        assert_eq!(result, 117440);
+    }
+
+    #[test]
+    fn test_find_quine_input() {
+        let result = find_quine(&computer("./input.txt"), 0).unwrap();
+
+        assert_eq!(result, 202991746427434);
+    }
+
+    #[test]
+    fn test_no_quine() {
+        let result = find_quine(&computer("./input_example.txt"), 0);
+
+        assert!(matches!(result, Err(QuineError::NoQuine)));
+    }
+
+    #[test]
+    fn test_not_amenable() {
+        let mut prototype = Computer::new();
+        // outputs a % 8 forever unless a is 0, and never shifts it
+        prototype.program = vec![5, 4, 3, 0];
+
+        let result = find_quine(&prototype, 64);
+
+        assert!(matches!(
+            result,
+            Err(QuineError::NotAmenable(ShiftCount(0), 64))
+        ));
+    }
+}
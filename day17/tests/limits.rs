@@ -0,0 +1,35 @@
+use day17::{Computer, Limits, RuntimeError};
+
+fn looping() -> Computer {
+    "
+        Register A: 1
+        Register B: 0
+        Register C: 0
+
+        Program: 1,1,3,0
+    "
+    .parse()
+    .unwrap()
+}
+
+#[test]
+fn test_set_limits() {
+    let mut computer = looping();
+    computer.set_limits(Limits {
+        max_steps: Some(50),
+        detect_cycles: false,
+    });
+
+    assert!(matches!(
+        computer.run(),
+        Err(RuntimeError::StepBudgetExhausted(50))
+    ));
+
+    let mut computer = looping();
+    computer.set_limits(Limits::default());
+
+    assert!(matches!(
+        computer.run(),
+        Err(RuntimeError::CycleDetected(..))
+    ));
+}
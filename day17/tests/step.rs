@@ -0,0 +1,39 @@
+use day17::{Computer, StepOutcome};
+
+#[test]
+fn test_step_to_halted() {
+    let mut computer: Computer = "
+        Register A: 3
+        Register B: 0
+        Register C: 0
+
+        Program: 0,1,5,4,3,0
+    "
+    .parse()
+    .unwrap();
+
+    let mut outcomes = vec![];
+    loop {
+        let outcome = computer.step().unwrap();
+        outcomes.push(outcome);
+        if outcome == StepOutcome::Halted {
+            break;
+        }
+    }
+
+    // a goes 3, 1, 0: two trips round the loop, and out of it the second time
+    assert_eq!(
+        outcomes,
+        vec![
+            StepOutcome::Advanced,
+            StepOutcome::Output(1),
+            StepOutcome::Jumped(0),
+            StepOutcome::Advanced,
+            StepOutcome::Output(0),
+            StepOutcome::Advanced,
+            StepOutcome::Halted,
+        ]
+    );
+    assert_eq!(computer.output_as_string(), "1,0");
+    assert_eq!(computer.step().unwrap(), StepOutcome::Halted);
+}
@@ -1,58 +1,45 @@
+use aoc_grid::{Grid, XY};
 use std::{
-    collections::{HashMap, HashSet},
-    fmt::{Display, Formatter, Write},
+    collections::HashSet,
+    fmt::{Display, Formatter},
 };
 
 #[allow(unused)]
-type Maze = HashSet<XY>;
+type Maze = Grid<bool>;
 #[allow(unused)]
 type Visited = HashSet<XY>;
-type XY = (usize, usize);
-type Bounds = (XY, XY);
 
 #[allow(unused)]
 pub(crate) struct FormattedMaze {
-    tiles: HashMap<XY, char>,
-    bounds: Bounds,
+    tiles: Grid<char>,
 }
 
 impl Display for FormattedMaze {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let ((x_min, y_min), (x_max, y_max)) = self.bounds;
-
-        for y in y_min..=y_max {
-            for x in x_min..=x_max {
-                let tile = self.tiles.get(&(x, y)).unwrap_or(&'.');
-                f.write_char(*tile)?;
-            }
-
-            f.write_str("\n")?;
-        }
-
-        Ok(())
+        self.tiles.fmt(f)
     }
 }
 
-impl From<(&Maze, Bounds)> for FormattedMaze {
-    fn from((maze, bounds): (&Maze, Bounds)) -> Self {
-        let maze: HashMap<XY, char> = maze.iter().map(|xy| (*xy, '#')).collect();
+impl From<&Maze> for FormattedMaze {
+    fn from(maze: &Maze) -> Self {
+        let tiles = maze.map(|wall| match wall {
+            true => '#',
+            false => '.',
+        });
 
-        let mut tiles = HashMap::new();
-        tiles.extend(maze);
-
-        FormattedMaze { tiles, bounds }
+        FormattedMaze { tiles }
     }
 }
 
-impl From<(&Maze, &Visited, Bounds)> for FormattedMaze {
-    fn from((maze, visited, bounds): (&Maze, &Visited, Bounds)) -> Self {
-        let maze: HashMap<XY, char> = maze.iter().map(|xy| (*xy, '#')).collect();
-        let visited: HashMap<XY, char> = visited.iter().map(|xy| (*xy, '*')).collect();
-
-        let mut tiles = HashMap::new();
-        tiles.extend(maze);
-        tiles.extend(visited);
+impl From<(&Maze, &Visited)> for FormattedMaze {
+    fn from((maze, visited): (&Maze, &Visited)) -> Self {
+        let mut tiles = FormattedMaze::from(maze).tiles;
+        for xy in visited {
+            if let Some(tile) = tiles.get_mut(*xy) {
+                *tile = '*';
+            }
+        }
 
-        FormattedMaze { tiles, bounds }
+        FormattedMaze { tiles }
     }
 }
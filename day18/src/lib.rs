@@ -0,0 +1,116 @@
+mod fmt_maze;
+mod walk;
+
+use aoc_grid::{Grid, XY};
+use aoc_solution::Solution;
+use indicatif::ProgressIterator;
+use itertools::Itertools;
+use std::error::Error;
+use thiserror::Error;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    fn part_1(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        Ok(part_1(input, 1024, XY(70, 70))?.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        let XY(x, y) = part_2(input, XY(70, 70))?;
+        Ok(format!("{x},{y}"))
+    }
+}
+
+#[derive(Debug, Error)]
+enum Day18Error {
+    #[error("Invalid Coordinate: {0}")]
+    InvalidCoordinate(String),
+
+    #[error("Coordinate {0} is outside the memory space")]
+    OutOfBounds(XY),
+
+    #[error("Unable to walk")]
+    WalkError(#[from] walk::Error),
+
+    #[error("Was always able to walk from start to finish")]
+    NeverBlocked,
+}
+
+use Day18Error::*;
+
+fn part_1(input: &str, first: usize, end: XY) -> Result<usize, Day18Error> {
+    let mut maze = empty_maze(end);
+    for line in input.trim().lines().take(first) {
+        corrupt(&mut maze, parse_line(line)?)?;
+    }
+
+    Ok(walk::shortest_path_length(&maze, XY(0, 0), end)?)
+}
+
+fn part_2(input: &str, end: XY) -> Result<XY, Day18Error> {
+    let coordinates: Vec<XY> = input.trim().lines().map(parse_line).try_collect()?;
+    let mut maze = empty_maze(end);
+
+    for xy in coordinates.iter().progress() {
+        corrupt(&mut maze, *xy)?;
+
+        match walk::shortest_path_length(&maze, XY(0, 0), end) {
+            Ok(_) => {
+                continue;
+            }
+            Err(e) => match e {
+                walk::Error::NoPathFound => return Ok(*xy),
+            },
+        }
+    }
+
+    Err(NeverBlocked)
+}
+
+/// a memory space from the origin to `end` without anything corrupted yet
+fn empty_maze(end: XY) -> Grid<bool> {
+    Grid::new(end.x() + 1, end.y() + 1, false)
+}
+
+fn corrupt(maze: &mut Grid<bool>, xy: XY) -> Result<(), Day18Error> {
+    *maze.get_mut(xy).ok_or(OutOfBounds(xy))? = true;
+    Ok(())
+}
+
+fn parse_line(line: &str) -> Result<XY, Day18Error> {
+    line.trim()
+        .split(",")
+        .flat_map(|c| c.parse::<usize>())
+        .collect_tuple()
+        .map(|(x, y)| XY(x, y))
+        .ok_or(InvalidCoordinate(line.to_string()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs::read_to_string;
+
+    #[test]
+    fn test_part_1_example() {
+        let input = read_to_string("./input_example.txt").unwrap();
+        let result = part_1(&input, 12, XY(6, 6)).unwrap();
+
+        assert_eq!(result, 22);
+    }
+
+    #[test]
+    fn test_part_2_example() {
+        let input = read_to_string("./input_example.txt").unwrap();
+        let result = part_2(&input, XY(6, 6)).unwrap();
+
+        assert_eq!(result, XY(6, 1));
+    }
+
+    #[test]
+    fn test_out_of_bounds() {
+        let result = part_1("1,1\n7,0", 2, XY(6, 6));
+
+        assert!(matches!(result, Err(OutOfBounds(XY(7, 0)))));
+    }
+}
@@ -1,19 +1,27 @@
 mod is_possible;
 mod number_of_possibilities;
 
+use aoc_solution::Solution;
 use indicatif::ProgressIterator;
 use is_possible::is_possible;
 use number_of_possibilities::number_of_possibilities;
-use std::fs::read_to_string;
+use std::error::Error;
+use thiserror::Error;
 
-fn main() {
-    let input = read_to_string("./input.txt").unwrap();
+pub struct Day19;
 
-    println!("part_1: {}", part_1(&input).unwrap());
-    println!("part_2: {}", part_2(&input).unwrap());
+impl Solution for Day19 {
+    fn part_1(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        Ok(part_1(input)?.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        Ok(part_2(input)?.to_string())
+    }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Error)]
+#[error("Invalid input format")]
 struct InvalidInputFormat;
 
 fn part_1(input: &str) -> Result<usize, InvalidInputFormat> {
@@ -39,6 +47,7 @@ fn part_2(input: &str) -> Result<usize, InvalidInputFormat> {
 #[cfg(test)]
 mod test {
     use super::*;
+    use std::fs::read_to_string;
 
     #[test]
     fn test_part_1_example() {
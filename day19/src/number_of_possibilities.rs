@@ -1,17 +1,27 @@
-use memoize::memoize;
+use std::collections::HashMap;
 
-#[memoize(Ignore: towels)]
 pub(crate) fn number_of_possibilities(towels: &Vec<&str>, pattern: String) -> usize {
+    count(towels, &pattern, &mut HashMap::new())
+}
+
+// the cache is keyed only by the remaining pattern, so it must never be shared
+// between different sets of towels
+fn count<'a>(towels: &Vec<&str>, pattern: &'a str, cache: &mut HashMap<&'a str, usize>) -> usize {
+    if let Some(n) = cache.get(pattern) {
+        return *n;
+    }
+
     let exact_matches = towels.iter().filter(|t| **t == pattern).count();
 
     let sub_matches = towels
         .iter()
         .filter_map(|towel| {
             let sub_pattern = pattern.strip_prefix(towel)?;
-            Some(number_of_possibilities(towels, sub_pattern.to_string()))
+            Some(count(towels, sub_pattern, cache))
         })
         .sum::<usize>();
 
+    cache.insert(pattern, exact_matches + sub_matches);
     exact_matches + sub_matches
 }
 
@@ -1,65 +1,67 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
-use indicatif::{ProgressIterator, ProgressStyle};
+use aoc_grid::{Grid, XY};
 
-use crate::direction::Direction;
-use crate::find_shortest_path;
+use crate::find_shortest_path::distances_from;
 use crate::maze::Tile;
 
-type XY = (usize, usize);
+/// picoseconds saved -> the number of distinct cheats that save exactly that
+pub(crate) type Savings = BTreeMap<usize, usize>;
 
-pub(crate) fn find_cheats(grid: &HashMap<XY, Tile>, start: XY, max_length: usize) -> Vec<Vec<XY>> {
-    let shortest_path = find_shortest_path(grid, start).unwrap();
+/// finds every cheat lasting at most `max_duration` picoseconds that beats
+/// the honest time from `start` to `end`. A cheat is identified by the track
+/// tiles it starts and ends on, and lets the program move through walls for
+/// the manhattan distance between them. None if there's no honest way from
+/// `start` to `end` to beat.
+pub(crate) fn find_cheats(
+    grid: &Grid<Tile>,
+    start: XY,
+    end: XY,
+    max_duration: usize,
+) -> Option<Savings> {
+    let from_start = distances_from(grid, start);
+    let &honest_time = from_start.get(&end)?;
+    let to_end = distances_from(grid, end);
+    let mut savings = Savings::new();
 
-    let path_prefixes: Vec<Vec<XY>> = shortest_path
-        .iter()
-        .enumerate()
-        .map(|(i, _)| {
-            shortest_path
-                .iter()
-                .take(i + 1)
-                .cloned()
-                .collect::<Vec<XY>>()
-        })
-        .collect();
-
-    path_prefixes
-        .iter()
-        .progress_with_style(
-            ProgressStyle::with_template("{wide_bar} [{pos}/{len}] {eta}").unwrap(),
-        )
-        .flat_map(|prefix| {
-            if max_length < prefix.len() {
-                return vec![];
+    for (&cheat_start, &time_to_cheat) in from_start.iter() {
+        for (cheat_end, duration) in within_manhattan_distance(cheat_start, max_duration) {
+            let Some(&time_after_cheat) = to_end.get(&cheat_end) else {
+                continue;
+            };
+
+            let time = time_to_cheat + duration + time_after_cheat;
+            if time < honest_time {
+                *savings.entry(honest_time - time).or_default() += 1;
             }
+        }
+    }
+
+    Some(savings)
+}
 
-            Direction::iter()
-                .filter_map(|d| {
-                    let prefix = prefix.clone();
-                    let d = *d;
-                    let current = *prefix.last().unwrap();
-                    let next = d.cheat(current)?;
-                    if prefix.contains(&next) {
-                        return None;
-                    }
-
-                    let suffix = find_shortest_path(grid, next)?;
-                    let path = [prefix, suffix].concat();
-                    match path.len() <= max_length {
-                        true => Some(path),
-                        false => None,
-                    }
-                })
-                .collect()
+/// every point (and its distance) no more than `max_distance` steps away from
+/// `xy`, excluding `xy` itself
+fn within_manhattan_distance(xy: XY, max_distance: usize) -> impl Iterator<Item = (XY, usize)> {
+    let max_distance = max_distance as isize;
+
+    (-max_distance..=max_distance).flat_map(move |dy| {
+        let max_dx = max_distance - dy.abs();
+        (-max_dx..=max_dx).filter_map(move |dx| {
+            let distance = (dx.abs() + dy.abs()) as usize;
+            match distance {
+                0 => None,
+                _ => Some((xy.offset((dx, dy))?, distance)),
+            }
         })
-        .filter(|path| path.len() <= max_length)
-        .collect()
+    })
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
     use crate::maze::Maze;
+    use std::fs::read_to_string;
 
     #[test]
     fn test_no_cheats_possible() {
@@ -69,10 +71,20 @@ mod test {
         .parse()
         .unwrap();
 
-        let result: Vec<Vec<XY>> = find_cheats(&maze.grid, maze.start, 0);
-        let expected: Vec<Vec<XY>> = vec![];
+        let result = find_cheats(&maze.grid, maze.start, maze.end, 2).unwrap();
 
-        assert_eq!(result, expected);
+        assert_eq!(result, Savings::new());
+    }
+
+    #[test]
+    fn test_no_honest_path() {
+        let maze: Maze = "
+            S#E
+        "
+        .parse()
+        .unwrap();
+
+        assert_eq!(find_cheats(&maze.grid, maze.start, maze.end, 2), None);
     }
 
     #[test]
@@ -84,9 +96,85 @@ mod test {
         .parse()
         .unwrap();
 
-        let result: Vec<Vec<XY>> = find_cheats(&maze.grid, maze.start, 2);
-        let expected: Vec<Vec<XY>> = vec![vec![(0, 0), (2, 0)]];
+        let result = find_cheats(&maze.grid, maze.start, maze.end, 2).unwrap();
+
+        assert_eq!(result, Savings::from([(2, 1)]));
+    }
+
+    #[test]
+    fn test_cheat_too_short_to_pass_the_wall() {
+        let maze: Maze = "
+            S##E
+            ....
+        "
+        .parse()
+        .unwrap();
+
+        assert_eq!(
+            find_cheats(&maze.grid, maze.start, maze.end, 2).unwrap(),
+            Savings::new()
+        );
+        // straight through both walls takes 3 instead of 5
+        assert_eq!(
+            find_cheats(&maze.grid, maze.start, maze.end, 3).unwrap(),
+            Savings::from([(2, 1)])
+        );
+    }
+
+    #[test]
+    fn test_example_two_picosecond_cheats() {
+        let input = read_to_string("./input_example.txt").unwrap();
+        let maze: Maze = input.parse().unwrap();
+
+        let result = find_cheats(&maze.grid, maze.start, maze.end, 2).unwrap();
+
+        assert_eq!(
+            result,
+            Savings::from([
+                (2, 14),
+                (4, 14),
+                (6, 2),
+                (8, 4),
+                (10, 2),
+                (12, 3),
+                (20, 1),
+                (36, 1),
+                (38, 1),
+                (40, 1),
+                (64, 1),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_example_twenty_picosecond_cheats() {
+        let input = read_to_string("./input_example.txt").unwrap();
+        let maze: Maze = input.parse().unwrap();
+
+        let result: Savings = find_cheats(&maze.grid, maze.start, maze.end, 20)
+            .unwrap()
+            .into_iter()
+            .filter(|(saved, _count)| *saved >= 50)
+            .collect();
 
-        assert_eq!(result, expected);
+        assert_eq!(
+            result,
+            Savings::from([
+                (50, 32),
+                (52, 31),
+                (54, 29),
+                (56, 39),
+                (58, 25),
+                (60, 23),
+                (62, 20),
+                (64, 19),
+                (66, 12),
+                (68, 14),
+                (70, 12),
+                (72, 22),
+                (74, 4),
+                (76, 3),
+            ])
+        );
     }
 }
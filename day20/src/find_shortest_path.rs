@@ -1,68 +1,30 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 
-use itertools::Itertools;
-use memoize::memoize;
+use aoc_grid::{Grid, XY};
+use aoc_search::bfs;
 
-use crate::direction::Direction;
 use crate::maze::Tile;
 
-type XY = (usize, usize);
-
-/// finds the shortes path to End tile from the given start position. Panics if grid does not
-/// contain an End tile.
-pub(crate) fn find_shortest_path(grid: &HashMap<XY, Tile>, start: XY) -> Option<Vec<XY>> {
-    let (&end, _) = grid.iter().find(|(_xy, tile)| **tile == Tile::End).unwrap();
-    walk(grid, HashSet::new(), end, start)
-}
-
-#[memoize(Ignore: grid, Ignore: visited)]
-fn walk(grid: &HashMap<XY, Tile>, visited: HashSet<XY>, end: XY, current: XY) -> Option<Vec<XY>> {
-    match grid.get(&current) {
-        None | Some(Tile::Wall) => return None,
-        Some(Tile::End) => return Some(vec![current]),
-        _ if visited.contains(&current) => return None,
-        _ => {}
-    }
-
-    let visited = visited
-        .union(&HashSet::from([current]))
-        .cloned()
-        .collect::<HashSet<_>>();
-
-    Direction::iter()
-        .filter_map(|d| d.step(current))
-        .collect::<Vec<_>>()
-        .iter()
-        .sorted_by(|&&a, &&b| distance_2(a, end).cmp(&distance_2(b, end)))
-        .filter_map(|&next| Some([vec![current], walk(grid, visited.clone(), end, next)?].concat()))
-        .next() // first 
+/// the number of picoseconds it takes to get from `from` to every reachable
+/// tile without cheating
+pub(crate) fn distances_from(grid: &Grid<Tile>, from: XY) -> HashMap<XY, usize> {
+    let search = bfs([from], |&current| track_neighbors(grid, current), |_| false);
+    search.distances().clone()
 }
 
-/// finds the distance squared between a & b. Is useful for sorting
-/// a collection of points by their distance to some other point.
-fn distance_2(a: XY, b: XY) -> usize {
-    let (x_a, y_a) = a;
-    let (x_b, y_b) = b;
-    let dx = x_a.abs_diff(x_b);
-    let dy = y_a.abs_diff(y_b);
-
-    dx.pow(2) + dy.pow(2)
+fn track_neighbors(grid: &Grid<Tile>, xy: XY) -> impl Iterator<Item = XY> {
+    grid.neighbors(xy).filter(|next| grid[*next] != Tile::Wall)
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
     use crate::maze::Maze;
-    use Direction::*;
-
-    fn m(xy: XY, directions: Vec<Direction>) -> Vec<XY> {
-        let (_, path) = directions
-            .iter()
-            .fold((xy, vec![xy]), |(current, path), direction| {
-                let next = direction.step(current).unwrap();
-                (next, [path, vec![next]].concat())
-            });
-        path
+
+    fn time_to_end(maze: &Maze) -> Option<usize> {
+        distances_from(&maze.grid, maze.start)
+            .get(&maze.end)
+            .copied()
     }
 
     #[test]
@@ -73,9 +35,7 @@ mod test {
         .parse()
         .unwrap();
 
-        let result = find_shortest_path(&maze.grid, maze.start).unwrap();
-
-        assert_eq!(result, vec![(0, 0), (1, 0)]);
+        assert_eq!(time_to_end(&maze), Some(1));
     }
 
     #[test]
@@ -87,9 +47,7 @@ mod test {
         .parse()
         .unwrap();
 
-        let result = find_shortest_path(&maze.grid, maze.start).unwrap();
-
-        assert_eq!(result, m((0, 0), vec![South]));
+        assert_eq!(time_to_end(&maze), Some(1));
     }
 
     #[test]
@@ -101,9 +59,8 @@ mod test {
         .parse()
         .unwrap();
 
-        let result = find_shortest_path(&maze.grid, maze.start).unwrap();
-
-        assert_eq!(result, m((0, 0), vec![South, East, East, North]));
+        // south, east, east, north
+        assert_eq!(time_to_end(&maze), Some(4));
     }
 
     #[test]
@@ -115,8 +72,47 @@ mod test {
         .parse()
         .unwrap();
 
-        let result = find_shortest_path(&maze.grid, maze.start).unwrap();
+        assert_eq!(time_to_end(&maze), Some(1));
+    }
 
-        assert_eq!(result, m((0, 0), vec![East]));
+    #[test]
+    fn test_start_surrounded_by_walls() {
+        let maze: Maze = "
+            S#E
+            #..
+        "
+        .parse()
+        .unwrap();
+
+        assert_eq!(time_to_end(&maze), None);
+        assert_eq!(distances_from(&maze.grid, maze.start).len(), 1);
+    }
+
+    #[test]
+    fn test_shortest_of_two_different_length_routes() {
+        // 8 steps along the bottom & up, or 16 steps over the top & around
+        let maze: Maze = "
+            .......
+            .#####.
+            .#E....
+            .###.#.
+            S....#.
+        "
+        .parse()
+        .unwrap();
+
+        assert_eq!(time_to_end(&maze), Some(8));
+    }
+
+    #[test]
+    fn test_two_mazes_back_to_back() {
+        // both mazes have S & E on the same tiles, so anything cached by
+        // tile alone would leak from one search into the other
+        let walled: Maze = "S#E\n...".parse().unwrap();
+        let open: Maze = "S.E\n...".parse().unwrap();
+
+        assert_eq!(time_to_end(&walled), Some(4));
+        assert_eq!(time_to_end(&open), Some(2));
+        assert_eq!(time_to_end(&walled), Some(4));
     }
 }
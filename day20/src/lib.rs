@@ -0,0 +1,79 @@
+mod find_cheats;
+mod find_shortest_path;
+mod maze;
+
+use find_cheats::find_cheats;
+use maze::Maze;
+use thiserror::Error;
+
+use crate::maze::MazeParseError;
+use aoc_solution::Solution;
+use std::error::Error;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    fn part_1(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        Ok(part_1(input, 100)?.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        Ok(part_2(input, 100)?.to_string())
+    }
+}
+
+#[derive(Debug, Error)]
+enum Day20Error {
+    #[error("Error parsing maze")]
+    MazeParseError(#[from] MazeParseError),
+
+    #[error("No path found in maze")]
+    NoPathFound,
+}
+
+fn part_1(input: &str, threshold: usize) -> Result<usize, Day20Error> {
+    count_cheats(input, 2, threshold)
+}
+
+fn part_2(input: &str, threshold: usize) -> Result<usize, Day20Error> {
+    count_cheats(input, 20, threshold)
+}
+
+/// the number of cheats lasting at most `max_duration` picoseconds that save
+/// at least `threshold` picoseconds
+fn count_cheats(input: &str, max_duration: usize, threshold: usize) -> Result<usize, Day20Error> {
+    let maze: Maze = input.parse()?;
+    let savings = find_cheats(&maze.grid, maze.start, maze.end, max_duration)
+        .ok_or(Day20Error::NoPathFound)?;
+
+    Ok(savings
+        .range(threshold..)
+        .map(|(_saved, count)| count)
+        .sum())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs::read_to_string;
+
+    #[test]
+    fn test_part_1_example() {
+        let input = read_to_string("./input_example.txt").unwrap();
+        let result = part_1(&input, 20).unwrap();
+        assert_eq!(result, 5);
+    }
+
+    #[test]
+    fn test_part_2_example() {
+        let input = read_to_string("./input_example.txt").unwrap();
+        let result = part_2(&input, 50).unwrap();
+        assert_eq!(result, 285);
+    }
+
+    #[test]
+    fn test_no_path() {
+        let result = part_1("S#E", 0);
+        assert!(matches!(result, Err(Day20Error::NoPathFound)));
+    }
+}
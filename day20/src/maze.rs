@@ -1,23 +1,17 @@
-use itertools::Itertools;
-use std::{collections::HashMap, str::FromStr};
+use aoc_grid::{Grid, GridParseError, XY};
+use std::str::FromStr;
 use thiserror::Error;
 
-type XY = (usize, usize);
-type Bounds = (XY, XY);
-
 pub(crate) struct Maze {
-    pub(crate) grid: HashMap<XY, Tile>,
-    #[allow(unused)]
-    pub(crate) bounds: Bounds,
+    pub(crate) grid: Grid<Tile>,
     pub(crate) start: XY,
-    #[allow(unused)]
     pub(crate) end: XY,
 }
 
 #[derive(Debug, Error)]
 pub(crate) enum MazeParseError {
     #[error("Failed to parse Tile")]
-    TileParseError(#[from] TileParseError),
+    TileParseError(#[from] GridParseError<TileParseError>),
 
     #[error("Start tile could not be found in maze")]
     StartNotFound,
@@ -30,40 +24,15 @@ impl FromStr for Maze {
     type Err = MazeParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let grid: HashMap<XY, Tile> = s
-            .trim()
-            .lines()
-            .enumerate()
-            .flat_map(|(y, line)| {
-                line.trim()
-                    .chars()
-                    .enumerate()
-                    .map(move |(x, c)| ((x, y), c))
-            })
-            .map(|(xy, c)| match Tile::try_from(c) {
-                Ok(t) => Ok((xy, t)),
-                Err(e) => Err(e),
-            })
-            .try_collect()?;
-
-        let max_x = *grid.keys().map(|(x, _y)| x).max().unwrap_or(&0);
-        let max_y = *grid.keys().map(|(_x, y)| y).max().unwrap_or(&0);
-        let (start, _) = grid
-            .iter()
-            .find(|(_xy, tile)| tile.is_start())
+        let grid: Grid<Tile> = s.parse()?;
+        let start = grid
+            .find(|tile| tile.is_start())
             .ok_or(MazeParseError::StartNotFound)?;
-
-        let (end, _) = grid
-            .iter()
-            .find(|(_xy, tile)| tile.is_end())
+        let end = grid
+            .find(|tile| tile.is_end())
             .ok_or(MazeParseError::EndNotFound)?;
 
-        Ok(Maze {
-            grid: grid.clone(),
-            bounds: ((0, 0), (max_x, max_y)),
-            start: *start,
-            end: *end,
-        })
+        Ok(Maze { grid, start, end })
     }
 }
 